@@ -0,0 +1,15 @@
+/// Order for playing the notes of a chord one at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArpeggioPattern {
+    /// Lowest to highest.
+    Up,
+    /// Highest to lowest.
+    Down,
+    /// Lowest to highest and back, without repeating the top or bottom note so the pattern loops.
+    UpDown,
+    /// Highest to lowest and back, without repeating the bottom or top note so the pattern loops.
+    DownUp,
+    /// Shuffled with the given seed.
+    Random(u64),
+}
@@ -0,0 +1,118 @@
+use crate::Chord;
+use core::cell::Cell;
+
+struct Entry {
+    chord: Chord,
+    buffer: Vec<f32>,
+    last_used: Cell<u64>,
+}
+
+/// Cache of rendered buffers for chords that evicts the least recently used chord when full.
+/// ```
+/// use staff::{midi, Chord};
+/// use staff::chord::ChordCache;
+///
+/// let render = |chord: Chord| chord.into_iter().map(|midi| midi.frequency() as f32).collect();
+///
+/// let c = Chord::major(midi!(C, 4));
+/// let f = Chord::major(midi!(F, 4));
+/// let g = Chord::major(midi!(G, 4));
+/// let mut cache = ChordCache::precompute(&[c.clone(), f.clone()], render);
+///
+/// assert_eq!(cache.get(&c).map(|buffer| buffer.len()), Some(3));
+///
+/// // F is the least recently used chord, so it is evicted first
+/// cache.insert(g.clone(), render(g.clone()));
+/// assert!(cache.get(&f).is_none());
+/// assert!(cache.get(&c).is_some());
+/// assert!(cache.get(&g).is_some());
+/// ```
+pub struct ChordCache {
+    capacity: usize,
+    entries: Vec<Entry>,
+    clock: Cell<u64>,
+}
+
+impl ChordCache {
+    /// Creates an empty cache that holds up to `capacity` chords.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+            clock: Cell::new(0),
+        }
+    }
+
+    /// Creates a cache of the buffer from `voicing_fn` for each of `chords`,
+    /// with room for exactly that many chords.
+    pub fn precompute(chords: &[Chord], voicing_fn: impl Fn(Chord) -> Vec<f32>) -> Self {
+        let mut cache = Self::new(chords.len());
+        for chord in chords {
+            cache.insert(chord.clone(), voicing_fn(chord.clone()));
+        }
+        cache
+    }
+
+    /// Returns the cached buffer for `chord`, marking it as recently used.
+    pub fn get(&self, chord: &Chord) -> Option<&[f32]> {
+        let entry = self.entries.iter().find(|entry| entry.chord == *chord)?;
+        entry.last_used.set(self.tick());
+        Some(&entry.buffer)
+    }
+
+    /// Caches `buffer` for `chord`, evicting the least recently used chord if the cache is full.
+    pub fn insert(&mut self, chord: Chord, buffer: Vec<f32>) {
+        let last_used = Cell::new(self.tick());
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.chord == chord) {
+            entry.buffer = buffer;
+            entry.last_used = last_used;
+            return;
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+        self.evict(self.capacity - 1);
+        self.entries.push(Entry {
+            chord,
+            buffer,
+            last_used,
+        });
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the number of chords this cache can hold, evicting the least recently used chords.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict(capacity);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn evict(&mut self, len: usize) {
+        while self.entries.len() > len {
+            let (idx, _) = self
+                .entries
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, entry)| entry.last_used.get())
+                .unwrap();
+            self.entries.swap_remove(idx);
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        let tick = self.clock.get() + 1;
+        self.clock.set(tick);
+        tick
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Chord;
+
+/// Chord progression that ends a phrase.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cadence {
+    /// V to I
+    Authentic(Chord, Chord),
+    /// Any chord to V
+    Half(Chord, Chord),
+    /// IV to I
+    Plagal(Chord, Chord),
+    /// V to vi
+    Deceptive(Chord, Chord),
+    /// iv6 to V in a minor key
+    PhrygianHalf(Chord, Chord),
+}
+
+impl Cadence {
+    /// Returns the pair of chords that make up this cadence.
+    pub fn chords(self) -> (Chord, Chord) {
+        match self {
+            Self::Authentic(from, to)
+            | Self::Half(from, to)
+            | Self::Plagal(from, to)
+            | Self::Deceptive(from, to)
+            | Self::PhrygianHalf(from, to) => (from, to),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::note::Accidental;
+use core::fmt;
+
+/// A figure above a bass note, with an optional accidental such as `#6` or `b3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Figure {
+    /// The diatonic interval above the bass, where 1 is the bass itself
+    pub number: u8,
+    /// The alteration of the note from the key signature
+    pub accidental: Option<Accidental>,
+}
+
+impl Figure {
+    pub const fn new(number: u8, accidental: Option<Accidental>) -> Self {
+        Self { number, accidental }
+    }
+
+    pub const fn sharp(number: u8) -> Self {
+        Self::new(number, Some(Accidental::Sharp))
+    }
+
+    pub const fn flat(number: u8) -> Self {
+        Self::new(number, Some(Accidental::Flat))
+    }
+}
+
+impl From<u8> for Figure {
+    fn from(number: u8) -> Self {
+        Self::new(number, None)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FiguredBassError {
+    /// A figure outside of 2 through 9
+    InvalidFigure(u8),
+    /// A combination of figures that isn't a triad or seventh chord
+    UnknownFigures,
+}
+
+impl fmt::Display for FiguredBassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFigure(number) => write!(f, "Invalid figure `{}`", number),
+            Self::UnknownFigures => f.write_str("Unknown combination of figures"),
+        }
+    }
+}
+
+/// Expands abbreviated figures to the full figures above the bass,
+/// returning them with the figure of the chord's root (1 for the bass).
+pub(super) fn expand(figures: &[Figure]) -> Result<([Figure; 3], usize, u8), FiguredBassError> {
+    if let Some(figure) = figures.iter().find(|f| !(2..=9).contains(&f.number)) {
+        return Err(FiguredBassError::InvalidFigure(figure.number));
+    }
+
+    let mut numbers = [0; 3];
+    for (number, figure) in numbers.iter_mut().zip(figures) {
+        *number = figure.number;
+    }
+    if figures.len() > numbers.len() {
+        return Err(FiguredBassError::UnknownFigures);
+    }
+    numbers[..figures.len()].sort_unstable_by(|a, b| b.cmp(a));
+
+    let (full, root): (&[u8], u8) = match &numbers[..figures.len()] {
+        [] | [5] | [3] | [5, 3] => (&[5, 3], 1),
+        [6] | [6, 3] => (&[6, 3], 6),
+        [6, 4] => (&[6, 4], 4),
+        [7] | [7, 3] | [7, 5] | [7, 5, 3] => (&[7, 5, 3], 1),
+        [6, 5] | [6, 5, 3] => (&[6, 5, 3], 6),
+        [4, 3] | [6, 4, 3] => (&[6, 4, 3], 4),
+        [2] | [4, 2] | [6, 4, 2] => (&[6, 4, 2], 2),
+        _ => return Err(FiguredBassError::UnknownFigures),
+    };
+
+    let mut expanded = [Figure::from(0); 3];
+    for (figure, &number) in expanded.iter_mut().zip(full) {
+        // Keep the accidental given for this number, if any
+        let accidental = figures
+            .iter()
+            .find(|f| f.number == number)
+            .and_then(|f| f.accidental);
+        *figure = Figure::new(number, accidental);
+    }
+
+    Ok((expanded, full.len(), root))
+}
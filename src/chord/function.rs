@@ -0,0 +1,179 @@
+use super::{RomanNumeral, TriadQuality};
+use crate::{scale::ScaleType, set::PitchSet, Chord, Interval, Key, Pitch, Scale};
+
+/// The harmonic function of a chord in a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HarmonicFunction {
+    Tonic,
+    Subdominant,
+    Dominant,
+}
+
+impl HarmonicFunction {
+    /// Returns the function of the chord on the 1-indexed `degree` of a key.
+    pub fn from_degree(degree: u8) -> Self {
+        match degree {
+            2 | 4 => Self::Subdominant,
+            5 | 7 => Self::Dominant,
+            _ => Self::Tonic,
+        }
+    }
+
+    /// Returns the 1-indexed degrees of a key that define this function.
+    fn characteristic_degrees(self) -> [u8; 2] {
+        match self {
+            Self::Tonic => [1, 3],
+            Self::Subdominant => [4, 6],
+            Self::Dominant => [7, 4],
+        }
+    }
+}
+
+/// Analysis of a chord's role in a key, created with [`Chord::identify_function`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChordFunction {
+    pub primary_function: HarmonicFunction,
+    /// The 1-indexed scale degree of the chord's root
+    pub degree: u8,
+    pub quality: TriadQuality,
+    pub roman_numeral: String,
+    /// True if every note of the chord is in the major scale of the key
+    pub is_diatonic: bool,
+    /// The key signature of the parallel mode this chord is borrowed from, if it is not diatonic
+    pub borrowed_from: Option<Key>,
+    /// The parallel mode with the same tonic this chord is borrowed from, if it is not diatonic
+    pub borrowed_mode: Option<ScaleType>,
+    /// The notes of the chord on the scale degrees that define its function,
+    /// like the tritone of a dominant chord
+    pub characteristic_notes: Vec<Pitch>,
+}
+
+impl ChordFunction {
+    pub(super) fn new(chord: Chord, key: Key) -> Self {
+        let tonic = key.tonic();
+        let numeral = RomanNumeral::from_chord(&chord, tonic);
+        let primary_function = HarmonicFunction::from_degree(numeral.degree);
+
+        let pitches = chord.pitch_set();
+        let is_in = |scale: PitchSet| pitches.bits & !scale.bits == 0;
+        let is_diatonic = is_in(Scale::major(tonic).collect());
+
+        // Look for the chord in the other modes with the same tonic
+        let borrowed_mode = if is_diatonic {
+            None
+        } else {
+            [
+                ScaleType::Aeolian,
+                ScaleType::Dorian,
+                ScaleType::Mixolydian,
+                ScaleType::Phrygian,
+                ScaleType::Lydian,
+                ScaleType::Locrian,
+            ]
+            .into_iter()
+            .find(|scale_type| is_in(Scale::new(tonic, scale_type.intervals()).collect()))
+        };
+        let borrowed_from = borrowed_mode
+            .and_then(|scale_type| Scale::new(tonic, scale_type.intervals()).get_key_signature());
+
+        let root = chord.root.pitch();
+        let is_diminished = numeral.quality == TriadQuality::Diminished;
+        let has_minor_seventh = pitches.contains(root + Interval::MINOR_SEVENTH);
+        let has_seventh = has_minor_seventh
+            || pitches.contains(root + Interval::MAJOR_SEVENTH)
+            || (is_diminished && pitches.contains(root + Interval::MAJOR_SIXTH));
+        let mut roman_numeral = numeral.to_string();
+        if is_diminished && has_minor_seventh {
+            // Half diminished chords are written with ø instead of °
+            roman_numeral.pop();
+            roman_numeral.push('ø');
+        }
+        if has_seventh {
+            roman_numeral.push('7');
+        }
+
+        let characteristic_notes = primary_function
+            .characteristic_degrees()
+            .into_iter()
+            .filter_map(|degree| Scale::major(tonic).nth(degree as usize - 1))
+            .filter(|pitch| pitches.contains(*pitch))
+            .collect();
+
+        Self {
+            primary_function,
+            degree: numeral.degree,
+            quality: numeral.quality,
+            roman_numeral,
+            is_diatonic,
+            borrowed_from,
+            borrowed_mode,
+            characteristic_notes,
+        }
+    }
+}
+
+/// The expected resolutions of a chord in a key, created with [`Chord::tension_and_resolution`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensionResolution {
+    /// How strongly the chord pulls towards a resolution, from 0 for a tonic chord to 1
+    pub tension_level: f32,
+    /// The chords this chord is likely to resolve to, from the strongest resolution
+    pub expected_resolution: Vec<Chord>,
+    /// The strength from 0 to 1 of each of the expected resolutions
+    pub resolution_strength: Vec<f32>,
+}
+
+impl TensionResolution {
+    pub(super) fn new(chord: Chord, key: Key) -> Self {
+        let function = ChordFunction::new(chord.clone(), key);
+        let mut tension_level = match function.primary_function {
+            HarmonicFunction::Tonic => 0.,
+            HarmonicFunction::Subdominant => 0.4,
+            HarmonicFunction::Dominant => 0.7,
+        };
+        if chord.clone().tritone().is_some() {
+            tension_level = 1f32.min(tension_level + 0.3);
+        }
+
+        let root = chord.root;
+        let mut resolutions = if let Some(resolutions) = chord.diminished7_resolutions() {
+            // Prefer the tonic of the key over the other enharmonic readings
+            resolutions
+                .into_iter()
+                .map(|(_, tonic)| {
+                    let strength = if tonic.root.pitch() == key.tonic() {
+                        1.
+                    } else {
+                        0.5
+                    };
+                    (tonic, strength)
+                })
+                .collect()
+        } else {
+            match function.degree {
+                // Authentic and deceptive cadences
+                5 => vec![
+                    (Chord::major(root + Interval::PERFECT_FOURTH), 1.),
+                    (Chord::minor(root + Interval::MAJOR_SECOND), 0.5),
+                ],
+                7 => vec![(Chord::major(root + Interval::MINOR_SECOND), 1.)],
+                // Towards the dominant, or the plagal cadence from IV
+                4 => vec![
+                    (Chord::major(root + Interval::MAJOR_SECOND), 0.8),
+                    (Chord::major(root + Interval::PERFECT_FIFTH), 0.5),
+                ],
+                2 => vec![(Chord::major(root + Interval::PERFECT_FOURTH), 0.8)],
+                _ => Vec::new(),
+            }
+        };
+        resolutions.sort_by(|(_, a): &(Chord, f32), (_, b)| b.total_cmp(a));
+
+        let (expected_resolution, resolution_strength) = resolutions.into_iter().unzip();
+        Self {
+            tension_level,
+            expected_resolution,
+            resolution_strength,
+        }
+    }
+}
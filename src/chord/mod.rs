@@ -2,15 +2,76 @@
 
 use crate::{
     fmt::Format,
-    midi::{MidiNote, Octave},
-    set::IntervalSet,
-    Interval, Natural, Note, Pitch,
+    instrument::{Instrument, RangeError},
+    midi::{MidiNote, MidiSet, Octave},
+    note::{Accidental, AccidentalPreference},
+    scale,
+    set::{IntervalSet, PitchSet},
+    Interval, Key, Natural, Note, Pitch, Scale,
 };
-use core::{fmt, str::FromStr};
+use core::{fmt, ops::RangeInclusive, str::FromStr};
+
+#[cfg(feature = "fretboard")]
+use crate::fretboard::GuitarTuning;
+
+#[cfg(feature = "std")]
+use crate::{
+    clef::{Clef, StaffPosition},
+    rng::SplitMix,
+    time::{Duration, DurationKind},
+    SignedInterval,
+};
+
+mod arpeggio;
+pub use self::arpeggio::ArpeggioPattern;
+
+mod cadence;
+pub use self::cadence::Cadence;
+
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+pub use self::cache::ChordCache;
+
+#[cfg(feature = "std")]
+mod progression;
+#[cfg(feature = "std")]
+pub use self::progression::{BassLineAnalysis, ChordProgression, HarmonicEvent};
+
+mod polychord;
+pub use self::polychord::PolyChord;
+
+mod pedal;
+pub use self::pedal::PedalRelation;
+
+mod roman;
+pub use self::roman::{RomanNumeral, TriadQuality};
+
+#[cfg(feature = "std")]
+mod function;
+#[cfg(feature = "std")]
+pub use self::function::{ChordFunction, HarmonicFunction, TensionResolution};
+
+mod figured_bass;
+pub use self::figured_bass::{Figure, FiguredBassError};
 
 mod iter;
 pub use self::iter::{Chords, Intervals, MidiNotes};
 
+mod satb;
+pub use self::satb::SatbVoicing;
+
+mod substitution;
+pub use self::substitution::SubstitutionType;
+
+mod upper_structure;
+pub use self::upper_structure::UpperStructureChord;
+
+#[cfg(feature = "std")]
+mod voice_leading;
+#[cfg(feature = "std")]
+pub use self::voice_leading::{ParallelMotion, VoiceCrossing, VoiceLeadingSolver};
+
 /*
 /// ```
 /// use staff::{chord, midi, Pitch, Chord};
@@ -32,6 +93,9 @@ where
 /// Chord made of the intervals from its bass note.
 ///
 /// A chord can be rootless, an inversion, or start from any bass note.
+///
+/// Chords are equal if they have the same notes, root and bass.
+/// Use [`Chord::eq_pitch_class_set`] to compare only their pitches.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chord {
@@ -57,9 +121,146 @@ impl Chord {
         }
     }
 
+    /// Returns this chord moved from `from_key` to `to_key` by the nearest interval
+    /// between their tonics, keeping its quality and voicing.
+    ///
+    /// Chords store MIDI notes, so notes can be spelled in `to_key` with
+    /// [`AccidentalPreference::KeyBased`](crate::note::AccidentalPreference::KeyBased).
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3));
+    /// let chord = chord.transpose_to_key(Key::major(Pitch::C), Key::major(Pitch::A));
+    ///
+    /// assert_eq!(chord, Chord::seventh(midi!(E, 3)));
+    ///
+    /// // Chords in the lowest octave are moved up instead
+    /// let chord = Chord::major(midi!(C, -1));
+    /// let chord = chord.transpose_to_key(Key::major(Pitch::C), Key::major(Pitch::A));
+    /// assert_eq!(chord, Chord::major(midi!(A, -1)));
+    /// ```
+    pub fn transpose_to_key(self, from_key: Key, to_key: Key) -> Self {
+        self.transpose_nearest(to_key.tonic() - from_key.tonic())
+    }
+
+    /// Returns this chord moved so that its root is `root`, by the nearest interval.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    ///
+    /// let chord = Chord::minor(midi!(A, 3)).with_root(Pitch::C);
+    /// assert_eq!(chord, Chord::minor(midi!(C, 4)));
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).first_inversion().with_root(Pitch::B);
+    /// assert_eq!(chord, Chord::major(midi!(B, 3)).first_inversion());
+    /// ```
+    pub fn with_root(self, root: Pitch) -> Self {
+        let interval = root - self.root.pitch();
+        self.transpose_nearest(interval)
+    }
+
+    /// Moves every note up by `interval`, or down by its inversion if that is closer
+    /// and stays above MIDI note 0.
+    fn transpose_nearest(self, interval: Interval) -> Self {
+        let up = interval.semitones();
+        let lowest = self.bass.map_or(self.root, |bass| bass.min(self.root));
+        let is_down = up > Interval::TRITONE.semitones()
+            && lowest.into_byte() + up >= Interval::OCTAVE.semitones();
+        let shift = |midi: MidiNote| {
+            if is_down {
+                MidiNote::from_byte(midi.into_byte() + up - Interval::OCTAVE.semitones())
+            } else {
+                midi + Interval::new(up)
+            }
+        };
+
+        Self {
+            root: shift(self.root),
+            bass: self.bass.map(shift),
+            ..self
+        }
+    }
+
+    /// Create a polychord of `upper` played over `lower`.
+    pub fn polychord(upper: Chord, lower: Chord) -> PolyChord {
+        PolyChord { upper, lower }
+    }
+
+    /// Returns an upper structure voicing of `triad` over this chord.
+    ///
+    /// The triad is moved to the octave above the bass of this chord.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let voicing = Chord::seventh(midi!(G, 3)).with_upper_structure(Chord::major(midi!(D, 3)));
+    /// let (lower, triad) = voicing.analyze();
+    ///
+    /// assert_eq!(lower, Chord::seventh(midi!(G, 3)));
+    /// assert_eq!(triad, Chord::major(midi!(D, 5)));
+    /// ```
+    pub fn with_upper_structure(self, triad: Chord) -> UpperStructureChord {
+        UpperStructureChord::new(self, triad)
+    }
+
+    /// Returns this chord with `bass_note` as its lowest note.
+    ///
+    /// `bass_note` replaces the note with the same pitch, if any.
+    /// Notes that would be at or below it are moved up by octaves,
+    /// and notes two or more octaves above it are moved down by octaves.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).with_bass(midi!(E, 4));
+    ///
+    /// assert_eq!(chord.to_string(), "C/E");
+    /// assert!(chord.into_iter().eq([midi!(E, 4), midi!(G, 4), midi!(C, 5)]));
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).with_bass(midi!(D, 3));
+    /// assert!(chord.into_iter().eq([midi!(D, 3), midi!(C, 4), midi!(E, 4), midi!(G, 4)]));
+    ///
+    /// let chord = Chord::seventh(midi!(G, 4)).with_bass(midi!(G, 2));
+    /// assert!(chord.into_iter().eq([midi!(G, 2), midi!(B, 3), midi!(D, 4), midi!(F, 4)]));
+    /// ```
     pub fn with_bass(mut self, bass_note: MidiNote) -> Self {
-        self.bass = Some(bass_note);
-        self
+        if self.intervals == IntervalSet::default() {
+            self.bass = Some(bass_note);
+            return self;
+        }
+
+        let mut intervals = IntervalSet::default();
+        intervals.push(Interval::UNISON);
+
+        let mut root = (self.root.pitch() == bass_note.pitch()).then_some(bass_note);
+        let mut has_bass = false;
+        for midi in self.clone() {
+            if !has_bass && midi.pitch() == bass_note.pitch() {
+                has_bass = true;
+                continue;
+            }
+
+            // Keep each note within the two octaves above the bass
+            let octave = Interval::OCTAVE.semitones() as i16;
+            let mut semitones = midi.into_byte() as i16 - bass_note.into_byte() as i16;
+            if semitones <= 0 {
+                semitones = octave - (-semitones).rem_euclid(octave);
+            }
+            while semitones >= 2 * octave {
+                semitones -= octave;
+            }
+            let interval = Interval::new(semitones as u8);
+
+            let midi = bass_note + interval;
+            if root.is_none() && midi.pitch() == self.root.pitch() {
+                root = Some(midi);
+            }
+            intervals.push(interval);
+        }
+
+        Self {
+            root: root.unwrap_or(self.root),
+            bass: Some(bass_note),
+            intervals,
+            ..self
+        }
     }
 
     pub fn with_inversion(mut self, bass_note: MidiNote) -> Self {
@@ -76,6 +277,84 @@ impl Chord {
         self.with_interval(Interval::UNISON)
     }
 
+    /// Returns true if this chord contains a note of its root pitch.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3));
+    /// assert!(chord.clone().has_root());
+    /// assert!(!chord.rootless_voicing().has_root());
+    /// ```
+    pub fn has_root(self) -> bool {
+        self.pitch_set().contains(self.root.pitch())
+    }
+
+    /// Returns the pitch of this chord's root, or `None` for a rootless chord.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    ///
+    /// let chord = Chord::minor_seventh(midi!(D, 3));
+    /// assert_eq!(chord.clone().root_pitch(), Some(Pitch::D));
+    /// assert_eq!(chord.rootless_voicing().root_pitch(), None);
+    /// ```
+    pub fn root_pitch(self) -> Option<Pitch> {
+        let root = self.root.pitch();
+        self.has_root().then_some(root)
+    }
+
+    /// Returns this chord with its bass note moved up an octave.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).first_inversion();
+    ///
+    /// assert_eq!(chord.to_string(), "C/E");
+    /// assert!(chord.into_iter().eq([midi!(E, 4), midi!(G, 4), midi!(C, 5)]));
+    /// ```
+    pub fn first_inversion(self) -> Self {
+        let mut root = self.root;
+        let mut notes = self.into_iter();
+        let Some(bass) = notes.next() else {
+            return Self::new(root);
+        };
+
+        let top = bass + Interval::OCTAVE;
+        if bass == root {
+            root = top;
+        }
+        Self::from_midi(root, notes.chain(core::iter::once(top))).unwrap()
+    }
+
+    /// Returns which inversion this chord is in from the chord tone in its lowest note:
+    /// 0 for the root, 1 for the third, 2 for the fifth, and 3 for the seventh.
+    ///
+    /// A ninth in the bass is the fourth inversion, and an eleventh is the fifth.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3));
+    /// assert_eq!(chord.clone().inversion_number(), 0);
+    ///
+    /// let chord = chord.first_inversion().first_inversion();
+    /// assert_eq!(chord.clone().inversion_number(), 2);
+    /// assert_eq!(chord.first_inversion().inversion_number(), 3);
+    /// ```
+    pub fn inversion_number(self) -> u8 {
+        let root = self.root.pitch();
+        let Some(bass) = self.into_iter().next() else {
+            return 0;
+        };
+
+        match (bass.pitch() - root).semitones() {
+            0 => 0,
+            3 | 4 => 1,
+            6..=8 => 2,
+            9..=11 => 3,
+            1 | 2 => 4,
+            _ => 5,
+        }
+    }
+
     /// ```
     /// use staff::{Chord, midi};
     ///
@@ -131,6 +410,14 @@ impl Chord {
             .with_interval(Interval::MINOR_SEVENTH)
     }
 
+    pub fn diminished_seventh(root: MidiNote) -> Self {
+        Self::new(root)
+            .root()
+            .with_interval(Interval::MINOR_THIRD)
+            .with_interval(Interval::TRITONE)
+            .with_interval(Interval::MAJOR_SIXTH)
+    }
+
     /// ```
     /// use staff::{midi, Chord, Pitch};
     ///
@@ -170,134 +457,2114 @@ impl Chord {
         })
     }
 
-    /// Returns the bass, or lowest, note of the chord.
+    /// Create a new Chord from `bass` in the fourth octave and each of `intervals` above it.
+    ///
+    /// Intervals must be less than 32 semitones and give different pitches.
     /// ```
-    /// use staff::{midi, Chord};
+    /// use staff::{midi, Chord, Interval, Pitch};
+    /// use staff::chord::Error;
     ///
-    /// let chord = Chord::major(midi!(C, 3))
-    ///     .with_bass(midi!(E, 4));
+    /// let intervals = [Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH, Interval::MINOR_SEVENTH];
+    /// let chord = Chord::from_bass_and_intervals(Pitch::G, &intervals);
+    /// assert_eq!(chord, Ok(Chord::seventh(midi!(G, 4))));
     ///
-    /// assert_eq!(chord.bass(), midi!(E, 4))
-    /// ```
-    /// Returns the root note if no other bass note is present.
-    /// ```
-    /// use staff::{midi, Chord};
+    /// let intervals = [Interval::MAJOR_THIRD, Interval::OCTAVE];
+    /// let chord = Chord::from_bass_and_intervals(Pitch::C, &intervals);
+    /// assert_eq!(chord, Err(Error::DuplicatePitch(Pitch::C)));
     ///
-    /// let chord = Chord::major(midi!(G, 4));
-    /// assert_eq!(chord.bass(), midi!(G, 4))
+    /// let chord = Chord::from_bass_and_intervals(Pitch::C, &[Interval::new(34)]);
+    /// assert_eq!(chord, Err(Error::TooWide));
     /// ```
-    pub fn bass(&self) -> MidiNote {
-        self.bass.unwrap_or(self.root)
+    pub fn from_bass_and_intervals(bass: Pitch, intervals: &[Interval]) -> Result<Self, Error> {
+        let bass = MidiNote::new(bass, Octave::FOUR);
+        let mut chord = Self::new(bass).root();
+        let mut pitches = PitchSet::default();
+        pitches.push(bass.pitch());
+
+        for &interval in intervals {
+            if interval >= Interval::new(32) {
+                return Err(Error::TooWide);
+            }
+
+            let pitch = (bass + interval).pitch();
+            if pitches.contains(pitch) {
+                return Err(Error::DuplicatePitch(pitch));
+            }
+            pitches.push(pitch);
+            chord.intervals.push(interval);
+        }
+        Ok(chord)
     }
 
+    /// Returns the interval above the root of each other note in this chord,
+    /// with notes voiced below the root taken within the octave above it.
     /// ```
-    /// use staff::{midi, Chord, Interval};
+    /// use staff::{midi, Chord, Interval, Pitch};
     ///
-    /// let chord = Chord::major(midi!(C, 4));
+    /// let chord = Chord::major(midi!(C, 4)).first_inversion();
+    /// let intervals = chord.intervals_above_root();
+    /// assert_eq!(intervals, [Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH]);
     ///
-    /// let intervals = [Interval::UNISON, Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH];
-    /// assert!(chord.intervals().eq(intervals));
+    /// let chord = Chord::from_bass_and_intervals(Pitch::C, &intervals).unwrap();
+    /// assert_eq!(chord, Chord::major(midi!(C, 4)));
     /// ```
-    pub fn intervals(self) -> Intervals {
-        self.into()
+    #[cfg(feature = "std")]
+    pub fn intervals_above_root(self) -> Vec<Interval> {
+        let root = self.root;
+        let mut intervals: Vec<Interval> = self
+            .into_iter()
+            .map(|midi| {
+                if midi >= root {
+                    midi - root
+                } else {
+                    midi.pitch() - root.pitch()
+                }
+            })
+            .filter(|interval| *interval != Interval::UNISON)
+            .collect();
+        intervals.sort();
+        intervals.dedup();
+        intervals
     }
-}
-
-impl FromIterator<MidiNote> for Chord {
-    fn from_iter<T: IntoIterator<Item = MidiNote>>(iter: T) -> Self {
-        let mut notes = iter.into_iter();
-        let root = notes.next().unwrap_or(MidiNote::from_byte(0));
 
-        Self::from_midi(root, core::iter::once(root).chain(notes)).unwrap()
-    }
-}
+    /// Create a new Chord from the notes at the given (1-indexed) degrees of a scale.
+    ///
+    /// The first degree is used as the root and each following note is placed above it.
+    /// ```
+    /// use staff::{midi, Chord, Scale};
+    /// use staff::scale::Error;
+    ///
+    /// let scale = Scale::major(midi!(C, 4));
+    /// let chord = Chord::from_scale_degrees(scale.clone(), &[1, 3, 5]).unwrap();
+    ///
+    /// assert_eq!(chord, Chord::major(midi!(C, 4)));
+    /// assert_eq!(Chord::from_scale_degrees(scale.clone(), &[1, 8]), Err(Error::InvalidDegree(8)));
+    /// assert_eq!(Chord::from_scale_degrees(scale, &[]), Err(Error::Empty));
+    /// ```
+    pub fn from_scale_degrees<S>(scale: S, degrees: &[u8]) -> Result<Self, scale::Error>
+    where
+        S: Iterator<Item = MidiNote> + Clone,
+    {
+        let nth = |degree: u8| {
+            degree
+                .checked_sub(1)
+                .and_then(|idx| scale.clone().nth(idx as usize))
+                .ok_or(scale::Error::InvalidDegree(degree))
+        };
 
-impl IntoIterator for Chord {
-    type Item = MidiNote;
+        let mut degrees = degrees.iter().copied();
+        let root = nth(degrees.next().ok_or(scale::Error::Empty)?)?;
 
-    type IntoIter = MidiNotes;
+        let mut chord = Self::new(root).root();
+        for degree in degrees {
+            let mut midi = nth(degree)?;
+            while midi < root {
+                midi = midi + Interval::OCTAVE;
+            }
+            chord.intervals.push(midi - root);
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into()
+        Ok(chord)
     }
-}
 
-impl fmt::Display for Chord {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.into_fmt().fmt(f)
+    /// Create the diatonic seventh chord on the given (1-indexed) degree of a scale.
+    /// ```
+    /// use staff::{midi, Chord, Scale};
+    /// use staff::scale::Error;
+    ///
+    /// let scale = Scale::major(midi!(C, 4));
+    /// let chord = Chord::major(midi!(C, 4)).major_seventh();
+    /// assert_eq!(Chord::diatonic_seventh(scale.clone(), 1), Ok(chord));
+    /// assert_eq!(Chord::diatonic_seventh(scale.clone(), 5), Ok(Chord::seventh(midi!(G, 4))));
+    /// assert_eq!(Chord::diatonic_seventh(scale.clone(), 7), Ok(Chord::half_diminished(midi!(B, 4))));
+    /// assert_eq!(Chord::diatonic_seventh(scale, 9), Err(Error::InvalidDegree(9)));
+    ///
+    /// let scale = Scale::harmonic_minor(midi!(A, 3));
+    /// let chord = Chord::diatonic_seventh(scale, 7).unwrap();
+    /// assert_eq!(chord, Chord::diminished_seventh(midi!(GSharp, 4)));
+    /// ```
+    pub fn diatonic_seventh<T, U>(scale: Scale<T, U>, degree: u8) -> Result<Self, scale::Error>
+    where
+        Scale<T, U>: Iterator<Item = MidiNote> + Clone,
+    {
+        scale.get_chord(degree, 4)
     }
-}
-
-impl FromStr for Chord {
-    type Err = ();
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
-        let natural: Natural = chars.next().unwrap().try_into().unwrap();
-
-        let mut next = chars.next();
-        let root: Pitch = match next {
-            Some('b') => {
-                next = chars.next();
-                if next == Some('b') {
-                    next = chars.next();
-                    Note::double_flat(natural).into()
-                } else {
-                    Note::flat(natural).into()
-                }
-            }
-            Some('#') => {
-                next = chars.next();
-                if next == Some('#') {
-                    next = chars.next();
-                    Note::double_sharp(natural).into()
-                } else {
-                    Note::sharp(natural).into()
-                }
-            }
-            _ => natural.into(),
+    /// Returns this chord with the tone at the given degree from the root altered by `alter`,
+    /// adding the tone if it isn't already in the chord.
+    ///
+    /// Alterations are applied to the major or perfect interval for each degree,
+    /// except for the 7th which is altered from the minor seventh used by dominant chords.
+    /// A natural resets the tone to its unaltered interval.
+    /// The chord is returned unchanged if the altered tone would be outside the MIDI range.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::note::Accidental;
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3)).alteration(9, Accidental::Flat);
+    ///
+    /// assert_eq!(chord.to_string(), "G7b9");
+    /// assert!(chord.is_altered());
+    ///
+    /// let chord = Chord::seventh(midi!(G, 8));
+    /// assert_eq!(chord.clone().alteration(13, Accidental::Flat), chord);
+    /// ```
+    pub fn alteration(self, degree: u8, alter: Accidental) -> Self {
+        let (default, range) = match degree {
+            1 => (0, 0..=0),
+            3 => (4, 3..=4),
+            5 => (7, 6..=8),
+            7 => (10, 9..=11),
+            2 | 9 => (14, 13..=15),
+            4 | 11 => (17, 16..=18),
+            6 | 13 => (21, 20..=22),
+            _ => return self,
+        };
+        let offset: i16 = match alter {
+            Accidental::DoubleFlat => -2,
+            Accidental::Flat => -1,
+            Accidental::Natural => 0,
+            Accidental::Sharp => 1,
+            Accidental::DoubleSharp => 2,
         };
 
-        let mut chord = match next {
-            Some('m') => {
-                next = chars.next();
-                Chord::minor(MidiNote::new(root, Octave::FOUR))
-            }
-            _ => Chord::major(MidiNote::new(root, Octave::FOUR)),
+        let root = self.root;
+        let altered = match u8::try_from(root.into_byte() as i16 + default + offset) {
+            Ok(byte) if byte <= 127 => byte,
+            _ => return self,
         };
 
-        loop {
-            if let Some(c) = next {
-                match c {
-                    'b' => match chars.next() {
-                        Some(c) => match c {
-                            '5' => chord.intervals.push(Interval::TRITONE),
-                            _ => todo!(),
-                        },
-                        None => break,
-                    },
-                    '7' => chord.intervals.push(Interval::MINOR_SEVENTH),
-                    _ => todo!(),
-                }
-                next = chars.next();
-            } else {
-                break;
-            }
+        let mut notes: MidiSet = self.clone().into_iter().collect();
+        if let Some(existing) = self
+            .into_iter()
+            .find(|midi| *midi >= root && range.contains(&(midi.into_byte() - root.into_byte())))
+        {
+            notes.remove(existing);
         }
-
-        Ok(chord)
+        notes.push(MidiNote::from_byte(altered));
+        Self::from_midi(root, notes).unwrap()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        midi::{MidiNote, Octave},
-        Chord, Pitch,
-    };
+    /// Returns this chord with the note at `interval` above the root.
+    /// ```
+    /// use staff::{midi, Chord, Interval};
+    ///
+    /// // A quartal chord of stacked fourths
+    /// let chord = Chord::new(midi!(D, 4))
+    ///     .root()
+    ///     .add_color_tone(Interval::PERFECT_FOURTH)
+    ///     .add_color_tone(Interval::MINOR_SEVENTH);
+    ///
+    /// assert!(chord.into_iter().eq([midi!(D, 4), midi!(G, 4), midi!(C, 5)]));
+    /// ```
+    pub fn add_color_tone(self, interval: Interval) -> Self {
+        let root = self.root;
+        let mut notes: MidiSet = self.into_iter().collect();
+        notes.push(root + interval);
+        Self::from_midi(root, notes).unwrap()
+    }
 
-    #[test]
-    fn it_parses_d_double_sharp_major() {
+    /// Returns this chord without the note at `interval` above the root,
+    /// or `None` if the chord doesn't contain it.
+    /// ```
+    /// use staff::{midi, Chord, Interval};
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).add_color_tone(Interval::new(18));
+    ///
+    /// let chord = chord.remove_color_tone(Interval::new(18)).unwrap();
+    /// assert_eq!(chord, Chord::major(midi!(C, 4)));
+    ///
+    /// assert!(chord.remove_color_tone(Interval::MAJOR_NINTH).is_none());
+    /// ```
+    pub fn remove_color_tone(self, interval: Interval) -> Option<Self> {
+        let note = self.root + interval;
+        if !self.clone().into_iter().any(|midi| midi == note) {
+            return None;
+        }
+        Some(self.retain(|midi| midi != note))
+    }
+
+    /// Returns true if this chord has an altered 5th, 9th, 11th, or 13th.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// assert!(!Chord::seventh(midi!(G, 3)).is_altered());
+    /// ```
+    pub fn is_altered(self) -> bool {
+        let intervals: IntervalSet = self.intervals().collect();
+        let has = |semitones| intervals.contains(Interval::new(semitones));
+
+        [13, 15, 18, 20].into_iter().any(has)
+            || (has(Interval::MAJOR_THIRD.semitones())
+                && !has(Interval::PERFECT_FIFTH.semitones())
+                && (has(Interval::TRITONE.semitones()) || has(Interval::MINOR_SIXTH.semitones())))
+    }
+
+    /// Returns this chord without any notes of its root pitch, keeping the root for its name.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::minor_seventh(midi!(D, 3)).rootless_voicing();
+    ///
+    /// assert_eq!(chord.to_string(), "Dm7(no root)");
+    /// assert!(chord.into_iter().eq([midi!(F, 3), midi!(A, 3), midi!(C, 4)]));
+    /// ```
+    pub fn rootless_voicing(self) -> Self {
+        let root = self.root.pitch();
+        self.retain(|midi| midi.pitch() != root)
+    }
+
+    /// Returns the guide tones of this chord: its root, third, and seventh.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3)).shell_voicing();
+    ///
+    /// assert_eq!(chord.to_string(), "G7(no5)");
+    /// assert!(chord.into_iter().eq([midi!(G, 3), midi!(B, 3), midi!(F, 4)]));
+    /// ```
+    pub fn shell_voicing(self) -> Self {
+        let root = self.root.pitch();
+        self.retain(|midi| {
+            matches!(
+                midi.pitch() - root,
+                Interval::UNISON
+                    | Interval::MINOR_THIRD
+                    | Interval::MAJOR_THIRD
+                    | Interval::MINOR_SEVENTH
+                    | Interval::MAJOR_SEVENTH
+            )
+        })
+    }
+
+    /// Returns this chord with only the notes matching `f`, keeping the same root.
+    fn retain(self, mut f: impl FnMut(MidiNote) -> bool) -> Self {
+        let Some(bass) = self.clone().into_iter().find(|midi| f(*midi)) else {
+            return Self::new(self.root);
+        };
+
+        let (bass, is_inversion) = if bass >= self.root {
+            (None, false)
+        } else {
+            (Some(bass), self.is_inversion)
+        };
+        let lowest = bass.unwrap_or(self.root);
+
+        let intervals = self
+            .clone()
+            .into_iter()
+            .filter(|midi| f(*midi))
+            .map(|midi| midi - lowest)
+            .collect();
+
+        Self {
+            root: self.root,
+            bass,
+            is_inversion,
+            intervals,
+        }
+    }
+
+    /// Returns the lowest sounding note of this chord, or `None` if it has no notes.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// assert_eq!(Chord::major(midi!(C, 4)).with_bass(midi!(G, 2)).bass_note(), Some(midi!(G, 2)));
+    /// assert_eq!(Chord::new(midi!(C, 4)).bass_note(), None);
+    /// ```
+    pub fn bass_note(self) -> Option<MidiNote> {
+        self.into_iter().next()
+    }
+
+    /// Create a new Chord from figured bass notation in the major `key`.
+    ///
+    /// Abbreviated figures are expanded, so `6` is a first inversion triad and `4 2` is a
+    /// third inversion seventh chord. Notes follow the key signature unless a figure has an accidental.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    /// use staff::chord::Figure;
+    ///
+    /// let key = Key::major(Pitch::C);
+    ///
+    /// let chord = Chord::from_figured_bass(midi!(E, 3), &[6.into()], key).unwrap();
+    /// assert_eq!(chord.to_string(), "C/E");
+    ///
+    /// let figures = [6.into(), 5.into()];
+    /// let chord = Chord::from_figured_bass(midi!(B, 2), &figures, key).unwrap();
+    /// assert_eq!(chord.to_string(), "G7/B");
+    ///
+    /// let chord = Chord::from_figured_bass(midi!(A, 2), &[Figure::sharp(3)], key).unwrap();
+    /// assert_eq!(chord.to_string(), "A");
+    /// ```
+    pub fn from_figured_bass(
+        bass: MidiNote,
+        figures: &[Figure],
+        key: Key,
+    ) -> Result<Self, FiguredBassError> {
+        let (figures, len, root_figure) = figured_bass::expand(figures)?;
+        let natural =
+            Note::from_pitch_with(bass.pitch(), AccidentalPreference::KeyBased(key)).natural;
+
+        let mut root = bass;
+        let mut intervals = IntervalSet::default();
+        intervals.push(Interval::UNISON);
+        for figure in &figures[..len] {
+            let natural = natural + (figure.number - 1);
+            let pitch = Pitch::from(Note::new(natural, key.accidental(natural)));
+            let pitch = match figure.accidental {
+                None => pitch,
+                Some(Accidental::Natural) => Pitch::from(natural),
+                Some(Accidental::Sharp) => pitch + Interval::MINOR_SECOND,
+                Some(Accidental::DoubleSharp) => pitch + Interval::MAJOR_SECOND,
+                Some(Accidental::Flat) => pitch - Interval::MINOR_SECOND,
+                Some(Accidental::DoubleFlat) => pitch - Interval::MAJOR_SECOND,
+            };
+
+            let octaves = (figure.number - 1) / 7;
+            let semitones =
+                (pitch - bass.pitch()).semitones() + octaves * Interval::OCTAVE.semitones();
+            let interval = Interval::new(semitones);
+            intervals.push(interval);
+
+            if figure.number == root_figure {
+                root = bass + interval;
+            }
+        }
+
+        let is_inversion = root != bass;
+        Ok(Self {
+            root,
+            bass: Some(bass).filter(|_| is_inversion),
+            is_inversion,
+            intervals,
+        })
+    }
+
+    /// Returns the bass, or lowest, note of the chord.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 3))
+    ///     .with_bass(midi!(E, 4));
+    ///
+    /// assert_eq!(chord.bass(), midi!(E, 4))
+    /// ```
+    /// Returns the root note if no other bass note is present.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(G, 4));
+    /// assert_eq!(chord.bass(), midi!(G, 4))
+    /// ```
+    pub fn bass(&self) -> MidiNote {
+        self.bass.unwrap_or(self.root)
+    }
+
+    /// ```
+    /// use staff::{midi, Chord, Interval};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    ///
+    /// let intervals = [Interval::UNISON, Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH];
+    /// assert!(chord.intervals().eq(intervals));
+    /// ```
+    pub fn intervals(self) -> Intervals {
+        self.into()
+    }
+
+    /// Returns the cadence formed by moving from `self` to `other` in the given major key,
+    /// or the phrygian half cadence of its relative minor.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    /// use staff::chord::Cadence;
+    ///
+    /// let key = Key::major(Pitch::C);
+    /// let dominant = Chord::major(midi!(G, 3));
+    /// let tonic = Chord::major(midi!(C, 4));
+    ///
+    /// let cadence = dominant.clone().is_cadential_with(tonic.clone(), key);
+    /// assert_eq!(cadence, Some(Cadence::Authentic(dominant, tonic)));
+    ///
+    /// // iv6 to V in A minor
+    /// let iv6 = Chord::minor(midi!(D, 4)).first_inversion();
+    /// let dominant = Chord::major(midi!(E, 4));
+    ///
+    /// let cadence = iv6.clone().is_cadential_with(dominant.clone(), key);
+    /// assert_eq!(cadence, Some(Cadence::PhrygianHalf(iv6, dominant)));
+    /// ```
+    pub fn is_cadential_with(self, other: Self, key: Key) -> Option<Cadence> {
+        let scale = Scale::major(MidiNote::new(key.tonic(), Octave::FOUR));
+        let is_degree = |chord: &Self, degree| {
+            scale.chord(degree, 3).map(|triad| triad.pitch_set()) == Some(chord.pitch_set())
+        };
+
+        let cadence = if is_degree(&self, 5) && is_degree(&other, 1) {
+            Cadence::Authentic(self, other)
+        } else if is_degree(&self, 5) && is_degree(&other, 6) {
+            Cadence::Deceptive(self, other)
+        } else if is_degree(&self, 4) && is_degree(&other, 1) {
+            Cadence::Plagal(self, other)
+        } else if is_degree(&self, 2)
+            && self.bass().pitch() == self.root.pitch() + Interval::MINOR_THIRD
+            && other.pitch_set() == Self::major(self.root + Interval::MAJOR_SECOND).pitch_set()
+        {
+            // ii of the major key is iv of its relative minor
+            Cadence::PhrygianHalf(self, other)
+        } else if !is_degree(&self, 5) && is_degree(&other, 5) {
+            Cadence::Half(self, other)
+        } else {
+            return None;
+        };
+        Some(cadence)
+    }
+
+    /// Returns the harmonic function of this chord in the major key of `key`.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    /// use staff::chord::{HarmonicFunction, TriadQuality};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let key = Key::major(Pitch::C);
+    ///
+    /// let function = Chord::seventh(midi!(G, 3)).identify_function(key);
+    /// assert_eq!(function.primary_function, HarmonicFunction::Dominant);
+    /// assert_eq!(function.degree, 5);
+    /// assert_eq!(function.roman_numeral, "V7");
+    /// assert!(function.is_diatonic);
+    /// assert_eq!(function.characteristic_notes, [Pitch::B, Pitch::F]);
+    ///
+    /// // iv borrowed from C minor, which has the key signature of E♭ major
+    /// let function = Chord::minor(midi!(F, 3)).identify_function(key);
+    /// assert_eq!(function.primary_function, HarmonicFunction::Subdominant);
+    /// assert_eq!(function.quality, TriadQuality::Minor);
+    /// assert_eq!(function.roman_numeral, "iv");
+    /// assert_eq!(function.borrowed_from, Some(Key::major(Pitch::DSharp)));
+    /// assert_eq!(function.borrowed_mode, Some(ScaleType::Aeolian));
+    ///
+    /// let function = Chord::half_diminished(midi!(B, 3)).identify_function(key);
+    /// assert_eq!(function.roman_numeral, "viiø7");
+    ///
+    /// let function = Chord::diminished_seventh(midi!(B, 3)).identify_function(key);
+    /// assert_eq!(function.roman_numeral, "vii°7");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn identify_function(self, key: Key) -> ChordFunction {
+        ChordFunction::new(self, key)
+    }
+
+    /// Returns how tense this chord is in the major key of `key`
+    /// and the chords it is likely to resolve to.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::C);
+    ///
+    /// let resolution = Chord::seventh(midi!(G, 3)).tension_and_resolution(key);
+    /// assert_eq!(resolution.tension_level, 1.);
+    /// assert_eq!(
+    ///     resolution.expected_resolution,
+    ///     [Chord::major(midi!(C, 4)), Chord::minor(midi!(A, 3))]
+    /// );
+    /// assert_eq!(resolution.resolution_strength, [1., 0.5]);
+    ///
+    /// // Each tonic a fully diminished seventh chord can lead to
+    /// let resolution = Chord::diminished_seventh(midi!(B, 3)).tension_and_resolution(key);
+    /// assert_eq!(resolution.expected_resolution.len(), 4);
+    /// assert_eq!(resolution.expected_resolution[0], Chord::major(midi!(C, 4)));
+    ///
+    /// let resolution = Chord::major(midi!(C, 4)).tension_and_resolution(key);
+    /// assert_eq!(resolution.tension_level, 0.);
+    /// assert!(resolution.expected_resolution.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn tension_and_resolution(self, key: Key) -> TensionResolution {
+        TensionResolution::new(self, key)
+    }
+
+    /// Returns the first pair of pitches a tritone apart in this chord, counting up from the root.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    ///
+    /// assert_eq!(Chord::seventh(midi!(G, 3)).tritone(), Some((Pitch::B, Pitch::F)));
+    /// assert_eq!(Chord::major(midi!(C, 4)).tritone(), None);
+    /// ```
+    pub fn tritone(self) -> Option<(Pitch, Pitch)> {
+        let pitches = self.pitch_set();
+        let root = self.root.pitch();
+        (0..Interval::OCTAVE.semitones())
+            .map(|semitones| root + Interval::new(semitones))
+            .find(|pitch| pitches.contains(*pitch) && pitches.contains(*pitch + Interval::TRITONE))
+            .map(|pitch| (pitch, pitch + Interval::TRITONE))
+    }
+
+    /// Returns true if the tritone in this chord resolves by contrary half steps
+    /// to notes of `resolution`, like B to C and F to E from G7 to C.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let c = Chord::major(midi!(C, 4));
+    /// assert!(Chord::seventh(midi!(G, 3)).resolves_tritone_to(c.clone()));
+    ///
+    /// // The tritone substitute shares the same tritone
+    /// assert!(Chord::seventh(midi!(CSharp, 4)).resolves_tritone_to(c.clone()));
+    ///
+    /// assert!(!Chord::seventh(midi!(D, 4)).resolves_tritone_to(c));
+    /// ```
+    pub fn resolves_tritone_to(self, resolution: Chord) -> bool {
+        let Some((low, high)) = self.tritone() else {
+            return false;
+        };
+
+        let pitches = resolution.pitch_set();
+        let step = Interval::MINOR_SECOND;
+        let is_inward = pitches.contains(low + step) && pitches.contains(high.sub_interval(step));
+        let is_outward = pitches.contains(low.sub_interval(step)) && pitches.contains(high + step);
+        is_inward || is_outward
+    }
+
+    /// Returns the directed interval between the bass notes of each pair of consecutive chords,
+    /// using the root unless a chord has another bass note.
+    /// ```
+    /// use staff::{midi, Chord, SignedInterval};
+    ///
+    /// let chords = [
+    ///     Chord::major(midi!(C, 3)),
+    ///     Chord::seventh(midi!(G, 2)).with_bass(midi!(B, 2)),
+    ///     Chord::minor(midi!(A, 2)),
+    /// ];
+    /// assert_eq!(
+    ///     Chord::bass_line_motion(&chords),
+    ///     [SignedInterval::new(-1), SignedInterval::new(-2)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn bass_line_motion(chords: &[Chord]) -> Vec<SignedInterval> {
+        chords
+            .windows(2)
+            .map(|pair| pair[0].bass().interval_to(pair[1].bass()))
+            .collect()
+    }
+
+    /// Returns how consonant this chord is, from 0 (most dissonant) to 1 (most consonant).
+    ///
+    /// Each pair of notes is ranked by Hindemith's series of intervals from most to least consonant:
+    /// unison, fifth, fourth, major third, minor sixth, minor third, major sixth,
+    /// minor seventh, major second, major seventh, minor second, and tritone.
+    /// The score is one minus the mean of the squared ranks, scaled from 0 to 1,
+    /// weighted by 5/4 so triads score about 0.85, dominant sevenths about 0.6,
+    /// and diminished sevenths about 0.4. Scores below 0 are clamped to 0.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let round = |score: f32| (score * 100.).round() / 100.;
+    ///
+    /// assert_eq!(round(Chord::major(midi!(C, 4)).stability_score()), 0.88);
+    /// assert_eq!(round(Chord::minor(midi!(C, 4)).stability_score()), 0.88);
+    /// assert_eq!(round(Chord::seventh(midi!(C, 4)).stability_score()), 0.6);
+    /// assert_eq!(round(Chord::diminished_seventh(midi!(C, 4)).stability_score()), 0.39);
+    /// ```
+    pub fn stability_score(self) -> f32 {
+        const RANKS: [u8; 12] = [0, 10, 8, 5, 3, 2, 11, 1, 4, 6, 7, 9];
+        const WEIGHT: f32 = 1.25;
+
+        let mut total = 0.;
+        let mut pairs = 0;
+        for (idx, low) in self.clone().into_iter().enumerate() {
+            for high in self.clone().into_iter().skip(idx + 1) {
+                let interval =
+                    high.into_byte().abs_diff(low.into_byte()) % Interval::OCTAVE.semitones();
+                let rank = RANKS[interval as usize] as f32 / 11.;
+                total += rank * rank;
+                pairs += 1;
+            }
+        }
+
+        if pairs == 0 {
+            1.
+        } else {
+            (1. - WEIGHT * total / pairs as f32).max(0.)
+        }
+    }
+
+    /// Returns a four-part voicing of this chord with each voice in its range.
+    ///
+    /// The bass sings the bass note of the chord and the upper voices never cross.
+    /// Doubling the bass note and close position are preferred,
+    /// with adjacent upper voices kept within an octave.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let voicing = Chord::major(midi!(C, 4))
+    ///     .voice_in_range(
+    ///         midi!(C, 4)..=midi!(G, 5),
+    ///         midi!(G, 3)..=midi!(D, 5),
+    ///         midi!(C, 3)..=midi!(G, 4),
+    ///         midi!(E, 2)..=midi!(C, 4),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(voicing.to_string(), "S: G4, A: E4, T: C4, B: C3");
+    /// ```
+    pub fn voice_in_range(
+        self,
+        soprano: RangeInclusive<MidiNote>,
+        alto: RangeInclusive<MidiNote>,
+        tenor: RangeInclusive<MidiNote>,
+        bass: RangeInclusive<MidiNote>,
+    ) -> Option<SatbVoicing> {
+        let pitches = self.pitch_set();
+        let required = pitches.count().min(4);
+        let bass_pitch = self.bass().pitch();
+
+        let notes = |range: &RangeInclusive<MidiNote>| {
+            (range.start().into_byte()..=range.end().into_byte())
+                .map(MidiNote::from_byte)
+                .filter(move |midi| pitches.contains(midi.pitch()))
+        };
+        let within_octave = |low: MidiNote, high: MidiNote| {
+            low < high && high.into_byte() - low.into_byte() <= Interval::OCTAVE.semitones()
+        };
+
+        let mut best: Option<((bool, u8, u8), SatbVoicing)> = None;
+        for bass in notes(&bass).filter(|midi| midi.pitch() == bass_pitch) {
+            for tenor in notes(&tenor).filter(|midi| *midi > bass) {
+                for alto in notes(&alto).filter(|midi| within_octave(tenor, *midi)) {
+                    for soprano in notes(&soprano).filter(|midi| within_octave(alto, *midi)) {
+                        let voicing = SatbVoicing {
+                            soprano,
+                            alto,
+                            tenor,
+                            bass,
+                        };
+
+                        let covered: PitchSet =
+                            voicing.into_iter().map(|midi| midi.pitch()).collect();
+                        if covered.count() < required {
+                            continue;
+                        }
+
+                        let is_doubled = required == 4
+                            || voicing
+                                .into_iter()
+                                .filter(|midi| midi.pitch() == bass_pitch)
+                                .count()
+                                > 1;
+                        let cost = (
+                            !is_doubled,
+                            soprano.into_byte() - tenor.into_byte(),
+                            soprano.into_byte() - bass.into_byte(),
+                        );
+                        let is_better = match best {
+                            Some((best_cost, _)) => cost < best_cost,
+                            None => true,
+                        };
+                        if is_better {
+                            best = Some((cost, voicing));
+                        }
+                    }
+                }
+            }
+        }
+
+        best.map(|(_, voicing)| voicing)
+    }
+
+    /// Returns every chord that can be made from `pitches`, with each pitch tried as the root.
+    ///
+    /// The first pitch is used as the bass note.
+    /// Chords are sorted by a likelihood score from 0 to 1, where simpler chords score higher.
+    /// ```
+    /// use staff::{Chord, Pitch};
+    ///
+    /// let chords = Chord::identify_all(&[Pitch::C, Pitch::E, Pitch::G, Pitch::A]);
+    /// let names: Vec<_> = chords.iter().map(|(chord, _)| chord.to_string()).collect();
+    ///
+    /// assert_eq!(names[0], "C(add6)");
+    /// assert_eq!(names[1], "Am7/C");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn identify_all(pitches: &[Pitch]) -> Vec<(Self, f32)> {
+        let Some(bass) = pitches.first() else {
+            return Vec::new();
+        };
+
+        let bass = MidiNote::new(*bass, Octave::FOUR);
+        let voicing: Vec<_> = pitches
+            .iter()
+            .map(|pitch| bass + (*pitch - bass.pitch()))
+            .collect::<crate::midi::MidiSet>()
+            .collect();
+
+        let mut chords: Vec<_> = voicing
+            .iter()
+            .filter_map(|root| Self::from_midi(*root, voicing.iter().copied()))
+            .map(|chord| {
+                let score = chord.likelihood();
+                (chord, score)
+            })
+            .collect();
+
+        chords.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        chords
+    }
+
+    /// Score how likely this chord is to be the intended name for its notes.
+    #[cfg(feature = "std")]
+    fn likelihood(&self) -> f32 {
+        let mut intervals: IntervalSet = self.clone().intervals().collect();
+        intervals.remove(Interval::UNISON);
+
+        let mut score = 1.;
+        if self.is_inversion {
+            score -= 0.1;
+        }
+
+        if intervals.maybe_remove(Interval::MAJOR_THIRD).is_none()
+            && intervals.maybe_remove(Interval::MINOR_THIRD).is_none()
+        {
+            score -= 0.2;
+        }
+
+        if intervals.maybe_remove(Interval::PERFECT_FIFTH).is_none() {
+            score -= if intervals.maybe_remove(Interval::TRITONE).is_some() {
+                0.05
+            } else {
+                0.1
+            };
+        }
+
+        let has_seventh = intervals.maybe_remove(Interval::MINOR_SEVENTH).is_some()
+            || intervals.maybe_remove(Interval::MAJOR_SEVENTH).is_some();
+        if has_seventh {
+            score -= 0.05;
+        }
+
+        for interval in intervals {
+            score -= match interval {
+                Interval::MAJOR_SECOND | Interval::PERFECT_FOURTH | Interval::MAJOR_SIXTH => 0.1,
+                _ => 0.2,
+            };
+        }
+
+        f32::max(score, 0.)
+    }
+
+    /// Returns the just intonation frequency ratio of each note above the root, in lowest terms.
+    ///
+    /// Intervals use the ratios of [`Interval::just_ratio`].
+    /// The altered fifths of augmented and diminished chords are tuned as stacked pure thirds,
+    /// so an augmented fifth is 25/16, a diminished fifth is 36/25, and a diminished seventh is 216/125.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// assert_eq!(chord.freq_ratios(), [(1, 1), (5, 4), (3, 2)]);
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3)).first_inversion();
+    /// assert_eq!(chord.freq_ratios(), [(1, 1), (5, 4), (3, 2), (9, 5)]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn freq_ratios(self) -> Vec<(u32, u32)> {
+        let quality = TriadQuality::of(&self);
+        let mut intervals: Vec<_> = self.intervals().collect();
+        intervals.sort();
+
+        intervals
+            .into_iter()
+            .map(|interval| {
+                let octaves = interval.semitones() / Interval::OCTAVE.semitones();
+                let (numerator, denominator) = match (interval.semitones() % 12, quality) {
+                    (6, TriadQuality::Diminished) => (36, 25),
+                    (8, TriadQuality::Augmented) => (25, 16),
+                    (9, TriadQuality::Diminished) => (216, 125),
+                    _ => interval.just_ratio(),
+                };
+
+                let numerator = numerator << octaves;
+                let divisor = gcd(numerator, denominator);
+                (numerator / divisor, denominator / divisor)
+            })
+            .collect()
+    }
+
+    /// Returns the number of distinct pitches in this chord.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// assert_eq!(Chord::seventh(midi!(G, 3)).size(), 4);
+    /// assert_eq!(Chord::new(midi!(C, 4)).size(), 0);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.pitch_set().count()
+    }
+
+    /// Returns true if this chord has no notes.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// assert!(Chord::new(midi!(C, 4)).is_empty());
+    /// assert!(!Chord::new(midi!(C, 4)).root().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.intervals.bits == 0
+    }
+
+    /// Returns the distinct pitches of this chord from the bass up.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).first_inversion();
+    /// assert!(chord.pitches().eq([Pitch::E, Pitch::G, Pitch::C]));
+    /// ```
+    pub fn pitches(&self) -> impl Iterator<Item = Pitch> {
+        let mut seen = PitchSet::default();
+        self.clone()
+            .into_iter()
+            .map(|midi| midi.pitch())
+            .filter(move |pitch| {
+                let is_new = !seen.contains(*pitch);
+                seen.push(*pitch);
+                is_new
+            })
+    }
+
+    /// Returns the pitch at `index` of [`Chord::pitches`].
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    ///
+    /// let chord = Chord::minor(midi!(A, 3));
+    /// assert_eq!(chord.pitch_at(1), Some(Pitch::C));
+    /// assert_eq!(chord.pitch_at(3), None);
+    /// ```
+    pub fn pitch_at(&self, index: usize) -> Option<Pitch> {
+        self.pitches().nth(index)
+    }
+
+    pub(crate) fn pitch_set(&self) -> PitchSet {
+        self.clone().into_iter().map(|midi| midi.pitch()).collect()
+    }
+
+    /// Returns the pitches of this chord in close position from the root in `starting_octave`,
+    /// with each note placed above the one before it in the chord's order from the root.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::midi::Octave;
+    ///
+    /// let chord = Chord::major(midi!(C, 4)).first_inversion();
+    /// assert_eq!(
+    ///     chord.as_pitch_notes(Octave::TWO),
+    ///     [midi!(C, 2), midi!(E, 2), midi!(G, 2)]
+    /// );
+    ///
+    /// let chord = Chord::seventh(midi!(G, 3));
+    /// assert_eq!(
+    ///     chord.as_pitch_notes(Octave::FOUR),
+    ///     [midi!(G, 4), midi!(B, 4), midi!(D, 5), midi!(F, 5)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn as_pitch_notes(self, starting_octave: Octave) -> Vec<MidiNote> {
+        let root = self.root.pitch();
+        let mut pitches: Vec<Pitch> = Vec::new();
+        for midi in self {
+            if !pitches.contains(&midi.pitch()) {
+                pitches.push(midi.pitch());
+            }
+        }
+        if let Some(idx) = pitches.iter().position(|pitch| *pitch == root) {
+            pitches.rotate_left(idx);
+        }
+
+        let mut notes: Vec<MidiNote> = Vec::with_capacity(pitches.len());
+        for pitch in pitches {
+            let note = match notes.last() {
+                Some(&prev) => prev + Interval::new((pitch - prev.pitch()).semitones()),
+                None => MidiNote::new(pitch, starting_octave),
+            };
+            notes.push(note);
+        }
+        notes
+    }
+
+    /// Returns the notes of this chord from the bass up, each moved by the number of octaves
+    /// at the same position in `voicing`.
+    ///
+    /// Notes without an offset are kept in place, and notes moved outside of the MIDI range are dropped.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// assert_eq!(
+    ///     chord.as_pitch_notes_with_voicing(&[-1, 0, 1]),
+    ///     [midi!(C, 3), midi!(E, 4), midi!(G, 5)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn as_pitch_notes_with_voicing(self, voicing: &[i8]) -> Vec<MidiNote> {
+        self.into_iter()
+            .enumerate()
+            .filter_map(|(idx, midi)| {
+                let octaves = voicing.get(idx).copied().unwrap_or(0) as i16;
+                let byte = midi.into_byte() as i16 + octaves * Interval::OCTAVE.semitones() as i16;
+                u8::try_from(byte)
+                    .ok()
+                    .filter(|byte| *byte <= 127)
+                    .map(MidiNote::from_byte)
+            })
+            .collect()
+    }
+
+    /// Returns this chord revoiced from the bass up in the given order, where `order[i]`
+    /// is the index of the note from the bass of this chord to place at position `i`.
+    ///
+    /// Each note is moved up by octaves to sit above the one before it,
+    /// and notes missing from `order` are left out.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::chord::Error;
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    ///
+    /// let voicing = chord.clone().reorder_voices(&[0, 2, 1]).unwrap();
+    /// assert!(voicing.into_iter().eq([midi!(C, 4), midi!(G, 4), midi!(E, 5)]));
+    ///
+    /// let voicing = chord.clone().reorder_voices(&[1, 2, 0]).unwrap();
+    /// assert_eq!(voicing, Chord::major(midi!(C, 4)).first_inversion());
+    ///
+    /// assert_eq!(chord.clone().reorder_voices(&[0, 3]), Err(Error::InvalidVoice(3)));
+    /// assert_eq!(chord.reorder_voices(&[0, 0, 1]), Err(Error::DuplicateVoice(0)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn reorder_voices(self, order: &[usize]) -> Result<Self, Error> {
+        let notes: Vec<MidiNote> = self.clone().into_iter().collect();
+
+        let mut voicing: Vec<MidiNote> = Vec::with_capacity(order.len());
+        for (pos, &idx) in order.iter().enumerate() {
+            let mut note = *notes.get(idx).ok_or(Error::InvalidVoice(idx))?;
+            if order[..pos].contains(&idx) {
+                return Err(Error::DuplicateVoice(idx));
+            }
+
+            if let Some(&prev) = voicing.last() {
+                note = prev + Interval::new((note.pitch() - prev.pitch()).semitones());
+                if note == prev {
+                    note = note + Interval::OCTAVE;
+                }
+            }
+            voicing.push(note);
+        }
+
+        match (voicing.first(), voicing.last()) {
+            (Some(&low), Some(&high)) if high - low >= Interval::new(32) => Err(Error::TooWide),
+            _ => {
+                let root = voicing
+                    .iter()
+                    .copied()
+                    .find(|note| note.pitch() == self.root.pitch())
+                    .unwrap_or(self.root);
+                Ok(Self::from_midi(root, voicing).unwrap_or(Self::new(root)))
+            }
+        }
+    }
+
+    /// Returns up to `max_voicings` voicings of this chord's pitches between `low` and `high`,
+    /// with the root in the bass and each pitch voiced once.
+    ///
+    /// Voicings are sorted from lowest to highest by their notes from the bass up.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// let voicings = chord.clone().root_position_permutations(midi!(C, 3), midi!(C, 5), 10);
+    ///
+    /// assert_eq!(voicings.len(), 5);
+    /// assert_eq!(voicings[0], [midi!(C, 3), midi!(E, 3), midi!(G, 3)]);
+    /// assert_eq!(voicings[2], [midi!(C, 3), midi!(G, 3), midi!(E, 4)]);
+    /// assert_eq!(voicings[4], [midi!(C, 4), midi!(E, 4), midi!(G, 4)]);
+    ///
+    /// let voicings = chord.root_position_permutations(midi!(C, 3), midi!(C, 5), 2);
+    /// assert_eq!(voicings.len(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn root_position_permutations(
+        self,
+        low: MidiNote,
+        high: MidiNote,
+        max_voicings: usize,
+    ) -> Vec<Vec<MidiNote>> {
+        fn permute(
+            voicing: &mut Vec<MidiNote>,
+            pitches: &[Pitch],
+            high: MidiNote,
+            max_voicings: usize,
+            voicings: &mut Vec<Vec<MidiNote>>,
+        ) {
+            let Some((&pitch, rest)) = pitches.split_first() else {
+                let mut notes = voicing.clone();
+                notes.sort();
+                voicings.push(notes);
+                return;
+            };
+
+            let bass = voicing[0];
+            let mut note = bass + Interval::new((pitch - bass.pitch()).semitones());
+            while note <= high && voicings.len() < max_voicings {
+                voicing.push(note);
+                permute(voicing, rest, high, max_voicings, voicings);
+                voicing.pop();
+                note = note + Interval::OCTAVE;
+            }
+        }
+
+        let root = self.root.pitch();
+        let pitches: Vec<Pitch> = self.pitch_set().filter(|pitch| *pitch != root).collect();
+
+        let mut voicings = Vec::new();
+        let mut bass = low + Interval::new((root - low.pitch()).semitones());
+        while bass <= high && voicings.len() < max_voicings {
+            permute(&mut vec![bass], &pitches, high, max_voicings, &mut voicings);
+            bass = bass + Interval::OCTAVE;
+        }
+
+        voicings.sort();
+        voicings
+    }
+
+    /// Returns the voicing of this chord with the same number of notes as `current_voicing`
+    /// that moves the least in total from it, for smooth voice leading.
+    ///
+    /// Voicings are searched within an octave either side of the middle of `current_voicing`
+    /// and use as many of the chord's pitches as there are voices.
+    /// Returns an empty `Vec` if `current_voicing` is empty
+    /// or has more voices than there are chord notes in that range.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let current = [midi!(C, 4), midi!(E, 4), midi!(G, 4)];
+    ///
+    /// let voicing = Chord::major(midi!(F, 3)).closest_voicing_to(&current);
+    /// assert_eq!(voicing, [midi!(C, 4), midi!(F, 4), midi!(A, 4)]);
+    ///
+    /// let voicing = Chord::seventh(midi!(G, 3)).closest_voicing_to(&current);
+    /// assert_eq!(voicing, [midi!(B, 3), midi!(F, 4), midi!(G, 4)]);
+    ///
+    /// // Only seven notes of C major lie between C3 and C5
+    /// let voicing = Chord::major(midi!(C, 4)).closest_voicing_to(&[midi!(C, 4); 8]);
+    /// assert!(voicing.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn closest_voicing_to(self, current_voicing: &[MidiNote]) -> Vec<MidiNote> {
+        fn search(
+            voicing: &mut Vec<MidiNote>,
+            candidates: &[MidiNote],
+            current: &[MidiNote],
+            pitch_count: usize,
+            best: &mut Option<(u32, Vec<MidiNote>)>,
+        ) {
+            if voicing.len() == current.len() {
+                let pitches: PitchSet = voicing.iter().map(|midi| midi.pitch()).collect();
+                if pitches.count() < pitch_count.min(current.len()) {
+                    return;
+                }
+
+                let distance = voicing
+                    .iter()
+                    .zip(current)
+                    .map(|(a, b)| a.into_byte().abs_diff(b.into_byte()) as u32)
+                    .sum();
+                if best.as_ref().is_none_or(|(min, _)| distance < *min) {
+                    *best = Some((distance, voicing.clone()));
+                }
+                return;
+            }
+
+            for (idx, &note) in candidates.iter().enumerate() {
+                voicing.push(note);
+                search(voicing, &candidates[idx + 1..], current, pitch_count, best);
+                voicing.pop();
+            }
+        }
+
+        let mut current = current_voicing.to_vec();
+        current.sort();
+        let (Some(low), Some(high)) = (current.first(), current.last()) else {
+            return Vec::new();
+        };
+
+        let middle = (low.into_byte() as i16 + high.into_byte() as i16) / 2;
+        let pitches = self.pitch_set();
+        let candidates: Vec<MidiNote> = (middle - 12..=middle + 12)
+            .filter(|byte| (0..=127).contains(byte))
+            .map(|byte| MidiNote::from_byte(byte as u8))
+            .filter(|midi| pitches.contains(midi.pitch()))
+            .collect();
+
+        let mut best = None;
+        search(
+            &mut Vec::with_capacity(current.len()),
+            &candidates,
+            &current,
+            pitches.count(),
+            &mut best,
+        );
+        best.map(|(_, voicing)| voicing).unwrap_or_default()
+    }
+
+    /// Returns the notes of this chord repeated over `octaves` octaves (at least one),
+    /// in the order of `pattern`.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::chord::ArpeggioPattern;
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    ///
+    /// let notes = chord.clone().arpeggiate(ArpeggioPattern::Up, 2);
+    /// assert_eq!(
+    ///     notes,
+    ///     [midi!(C, 4), midi!(E, 4), midi!(G, 4), midi!(C, 5), midi!(E, 5), midi!(G, 5)]
+    /// );
+    ///
+    /// let notes = chord.arpeggiate(ArpeggioPattern::UpDown, 1);
+    /// assert_eq!(notes, [midi!(C, 4), midi!(E, 4), midi!(G, 4), midi!(E, 4)]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn arpeggiate(self, pattern: ArpeggioPattern, octaves: u8) -> Vec<MidiNote> {
+        let notes: Vec<_> = self.into_iter().collect();
+        let mut arpeggio: Vec<_> = (0..octaves.max(1))
+            .flat_map(|octave| {
+                notes
+                    .iter()
+                    .map(move |midi| *midi + Interval::new(octave * Interval::OCTAVE.semitones()))
+            })
+            .collect();
+
+        match pattern {
+            ArpeggioPattern::Up => {}
+            ArpeggioPattern::Down => arpeggio.reverse(),
+            ArpeggioPattern::UpDown | ArpeggioPattern::DownUp => {
+                if pattern == ArpeggioPattern::DownUp {
+                    arpeggio.reverse();
+                }
+                let len = arpeggio.len();
+                if len > 2 {
+                    let back: Vec<_> = arpeggio[1..len - 1].iter().rev().copied().collect();
+                    arpeggio.extend(back);
+                }
+            }
+            ArpeggioPattern::Random(seed) => {
+                let mut rng = SplitMix(seed);
+                for idx in (1..arpeggio.len()).rev() {
+                    let other = (rng.next_u64() % (idx as u64 + 1)) as usize;
+                    arpeggio.swap(idx, other);
+                }
+            }
+        }
+
+        arpeggio
+    }
+
+    /// Returns the ii and V chords that lead into this chord as the I chord,
+    /// or `None` if this chord isn't a major or minor chord on a note of the major `key`.
+    ///
+    /// Minor chords are approached by a half diminished ii chord.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::C);
+    ///
+    /// let (ii, v) = Chord::major(midi!(C, 4)).get_ii_v(key).unwrap();
+    /// assert_eq!(ii, Chord::minor_seventh(midi!(D, 4)));
+    /// assert_eq!(v, Chord::seventh(midi!(G, 3)));
+    ///
+    /// let (ii, v) = Chord::minor(midi!(A, 3)).get_ii_v(key).unwrap();
+    /// assert_eq!(ii, Chord::half_diminished(midi!(B, 3)));
+    /// assert_eq!(v, Chord::seventh(midi!(E, 3)));
+    ///
+    /// assert!(Chord::major(midi!(CSharp, 4)).get_ii_v(key).is_none());
+    /// ```
+    pub fn get_ii_v(self, key: Key) -> Option<(Self, Self)> {
+        let ii = self.ii_chord(key)?;
+        let v = Self::seventh(MidiNote::from_byte(self.root.into_byte().checked_sub(5)?));
+        Some((ii, v))
+    }
+
+    /// Returns the ii chord and the tritone substitute of the V chord (♭II7)
+    /// that lead into this chord as the I chord.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::C);
+    /// let (ii, sub_v) = Chord::major(midi!(C, 4)).get_tritone_ii_v(key).unwrap();
+    ///
+    /// assert_eq!(ii, Chord::minor_seventh(midi!(D, 4)));
+    /// assert_eq!(sub_v, Chord::seventh(midi!(CSharp, 4)));
+    /// ```
+    pub fn get_tritone_ii_v(self, key: Key) -> Option<(Self, Self)> {
+        let ii = self.ii_chord(key)?;
+        let sub_v = Self::seventh(self.root + Interval::MINOR_SECOND);
+        Some((ii, sub_v))
+    }
+
+    fn ii_chord(&self, key: Key) -> Option<Self> {
+        self.root.pitch().diatonic_index(key)?;
+        let root = self.root + Interval::MAJOR_SECOND;
+        match TriadQuality::of(self) {
+            TriadQuality::Major => Some(Self::minor_seventh(root)),
+            TriadQuality::Minor => Some(Self::half_diminished(root)),
+            TriadQuality::Diminished | TriadQuality::Augmented => None,
+        }
+    }
+
+    /// Returns chords that can replace this chord in the major `key`, with the reason for each.
+    /// ```
+    /// use staff::{midi, Chord, Key, Pitch};
+    /// use staff::chord::SubstitutionType;
+    ///
+    /// let key = Key::major(Pitch::C);
+    /// let substitutions = Chord::seventh(midi!(G, 3)).common_substitutions(key);
+    ///
+    /// assert!(substitutions.contains(&(Chord::seventh(midi!(CSharp, 4)), SubstitutionType::Tritone)));
+    /// assert!(substitutions.contains(&(Chord::minor_seventh(midi!(G, 3)), SubstitutionType::Parallel)));
+    ///
+    /// let substitutions = Chord::major(midi!(C, 4)).common_substitutions(key);
+    /// assert!(substitutions.contains(&(Chord::minor(midi!(A, 4)), SubstitutionType::Relative)));
+    /// assert!(substitutions.contains(&(Chord::major(midi!(GSharp, 4)), SubstitutionType::Deceptive)));
+    ///
+    /// // Each chord is only listed once
+    /// for (idx, (chord, _)) in substitutions.iter().enumerate() {
+    ///     assert!(!substitutions[idx + 1..].iter().any(|(other, _)| other == chord));
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn common_substitutions(self, key: Key) -> Vec<(Self, SubstitutionType)> {
+        let root = self.root;
+        let pitches = self.pitch_set();
+        let has_fifth = pitches.contains(root.pitch() + Interval::PERFECT_FIFTH);
+        let has_seventh = pitches.contains(root.pitch() + Interval::MINOR_SEVENTH);
+        let quality = TriadQuality::of(&self);
+
+        let mut substitutions = Vec::new();
+        if !has_fifth {
+            return substitutions;
+        }
+
+        match quality {
+            TriadQuality::Major => {
+                substitutions.push((
+                    Self::minor(root + Interval::MAJOR_SIXTH),
+                    SubstitutionType::Relative,
+                ));
+
+                if has_seventh {
+                    substitutions.push((Self::minor_seventh(root), SubstitutionType::Parallel));
+                    substitutions.push((
+                        Self::seventh(root + Interval::TRITONE),
+                        SubstitutionType::Tritone,
+                    ));
+                } else {
+                    substitutions.push((Self::minor(root), SubstitutionType::Parallel));
+                }
+
+                // vi is already listed as the relative minor
+                if root.pitch() == key.tonic() {
+                    substitutions.push((
+                        Self::major(root + Interval::MINOR_SIXTH),
+                        SubstitutionType::Deceptive,
+                    ));
+                }
+            }
+            TriadQuality::Minor => {
+                substitutions.push((
+                    Self::major(root + Interval::MINOR_THIRD),
+                    SubstitutionType::Relative,
+                ));
+
+                if has_seventh {
+                    substitutions.push((Self::seventh(root), SubstitutionType::Parallel));
+                    substitutions.push((
+                        Self::major(root + Interval::MINOR_THIRD)
+                            .with_interval(Interval::MAJOR_SIXTH),
+                        SubstitutionType::AddedSixth,
+                    ));
+                } else {
+                    substitutions.push((Self::major(root), SubstitutionType::Parallel));
+                }
+            }
+            TriadQuality::Diminished | TriadQuality::Augmented => {}
+        }
+
+        substitutions
+    }
+
+    /// Returns the 9th, 11th, and 13th from the major `key` that can be added to this chord.
+    ///
+    /// Extensions a half step above a chord tone are avoided.
+    /// Each interval is measured from the root, so a b9 or #11 differs from the default by a semitone.
+    /// ```
+    /// use staff::{midi, Chord, Interval, Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::C);
+    /// let extensions = Chord::seventh(midi!(G, 3)).extensions_in_key(key);
+    ///
+    /// assert_eq!(
+    ///     extensions,
+    ///     [(Interval::MAJOR_NINTH, Pitch::A), (Interval::MAJOR_THIRTEENTH, Pitch::E)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn extensions_in_key(self, key: Key) -> Vec<(Interval, Pitch)> {
+        let root = self.root.pitch();
+        let pitches = self.pitch_set();
+        let octave = Interval::OCTAVE.semitones();
+
+        [
+            Interval::MAJOR_NINTH,
+            Interval::MAJOR_ELEVENTH,
+            Interval::MAJOR_THIRTEENTH,
+        ]
+        .into_iter()
+        .filter_map(|extension| {
+            let (interval, pitch) = [0, -1, 1].into_iter().find_map(|alteration: i8| {
+                let interval = Interval::new((extension.semitones() as i8 + alteration) as u8);
+                let pitch = root + Interval::new(interval.semitones() % octave);
+                pitch.diatonic_index(key).map(|_| (interval, pitch))
+            })?;
+
+            let is_avoided = pitches.contains(pitch - Interval::MINOR_SECOND);
+            (!pitches.contains(pitch) && !is_avoided).then_some((interval, pitch))
+        })
+        .collect()
+    }
+
+    /// Returns each note of this chord that is below a note of `other_voice`,
+    /// a chord sung or played by a lower part at the same time.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::chord::VoiceCrossing;
+    ///
+    /// let upper = Chord::from_midi(midi!(E, 4), [midi!(E, 4), midi!(C, 5)]).unwrap();
+    /// let lower = Chord::from_midi(midi!(C, 3), [midi!(C, 3), midi!(G, 4)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     upper.voice_cross_check(lower),
+    ///     [VoiceCrossing { voice: 0, crossing_with: 1, by_semitones: 3 }]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn voice_cross_check(self, other_voice: Chord) -> Vec<VoiceCrossing> {
+        let lower: Vec<_> = other_voice.into_iter().collect();
+
+        self.into_iter()
+            .enumerate()
+            .flat_map(|(voice, note)| {
+                lower
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, other)| **other > note)
+                    .map(move |(crossing_with, other)| VoiceCrossing {
+                        voice: voice as u8,
+                        crossing_with: crossing_with as u8,
+                        by_semitones: other.into_byte() - note.into_byte(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the parallel fifths and octaves between the voices of this part and `other`
+    /// as they move to `next_self` and `next_other`.
+    ///
+    /// Voices are matched from the bass up in each part, from one chord to the next.
+    /// Only voices moving in the same direction are parallel, so contrary fifths and octaves are allowed.
+    /// ```
+    /// use staff::{midi, Chord, Interval};
+    /// use staff::chord::ParallelMotion;
+    ///
+    /// let parallels = Chord::major(midi!(C, 4)).parallel_motion_check(
+    ///     Chord::new(midi!(C, 3)).root(),
+    ///     Chord::major(midi!(D, 4)),
+    ///     Chord::new(midi!(D, 3)).root(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     parallels,
+    ///     [
+    ///         ParallelMotion { voice: 0, with_voice: 0, interval: Interval::OCTAVE },
+    ///         ParallelMotion { voice: 2, with_voice: 0, interval: Interval::PERFECT_FIFTH },
+    ///     ]
+    /// );
+    ///
+    /// // Fifths in contrary motion
+    /// let parallels = Chord::new(midi!(C, 4)).root().parallel_motion_check(
+    ///     Chord::new(midi!(F, 3)).root(),
+    ///     Chord::new(midi!(D, 4)).root(),
+    ///     Chord::new(midi!(G, 2)).root(),
+    /// );
+    /// assert!(parallels.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parallel_motion_check(
+        self,
+        other: Chord,
+        next_self: Chord,
+        next_other: Chord,
+    ) -> Vec<ParallelMotion> {
+        let class = |a: MidiNote, b: MidiNote| {
+            Interval::new(a.into_byte().abs_diff(b.into_byte()) % Interval::OCTAVE.semitones())
+        };
+        let voices: Vec<_> = self.into_iter().zip(next_self).collect();
+        let others: Vec<_> = other.into_iter().zip(next_other).collect();
+
+        let mut parallels = Vec::new();
+        for (voice, &(note, next)) in voices.iter().enumerate() {
+            for (with_voice, &(other, other_next)) in others.iter().enumerate() {
+                let before = class(note, other);
+                let interval = match before {
+                    Interval::PERFECT_FIFTH => Interval::PERFECT_FIFTH,
+                    Interval::UNISON => Interval::OCTAVE,
+                    _ => continue,
+                };
+
+                let is_similar = note != next && (next > note) == (other_next > other);
+                if is_similar && other != other_next && class(next, other_next) == before {
+                    parallels.push(ParallelMotion {
+                        voice: voice as u8,
+                        with_voice: with_voice as u8,
+                        interval,
+                    });
+                }
+            }
+        }
+        parallels
+    }
+
+    /// Returns an error with the first note of this chord, from the bass up,
+    /// that is outside of the range of `instrument`.
+    /// ```
+    /// use staff::{midi, Chord, Instrument};
+    /// use staff::instrument::RangeError;
+    ///
+    /// let chord = Chord::major(midi!(C, 5));
+    /// assert_eq!(chord.clone().validate_for(Instrument::Soprano), Ok(()));
+    /// assert_eq!(
+    ///     chord.validate_for(Instrument::Alto),
+    ///     Err(RangeError { note: midi!(E, 5), instrument: Instrument::Alto })
+    /// );
+    /// ```
+    pub fn validate_for(self, instrument: Instrument) -> Result<(), RangeError> {
+        match self.into_iter().find(|note| !note.is_in_range(instrument)) {
+            Some(note) => Err(RangeError { note, instrument }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns true if both chords contain the same pitches, in any octave or inversion.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// let inversion = chord.clone().first_inversion();
+    ///
+    /// assert_ne!(chord, inversion);
+    /// assert!(chord.clone().equals_enharmonically(inversion));
+    /// assert!(!chord.equals_enharmonically(Chord::minor(midi!(C, 4))));
+    /// ```
+    pub fn equals_enharmonically(self, other: Self) -> bool {
+        self.pitch_set() == other.pitch_set()
+    }
+
+    /// Returns true if both chords contain the same pitch classes,
+    /// ignoring their octaves, order and root.
+    ///
+    /// This is the same as [`Chord::equals_enharmonically`].
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// let inversion = chord.clone().first_inversion();
+    ///
+    /// assert_ne!(chord, inversion);
+    /// assert!(chord.eq_pitch_class_set(inversion));
+    /// ```
+    pub fn eq_pitch_class_set(self, other: Self) -> bool {
+        self.equals_enharmonically(other)
+    }
+
+    /// Returns the chromatic mediants of this chord: the chords of the same quality
+    /// a major third above, a minor third above, a minor third below, and a major third below.
+    ///
+    /// Minor chords have minor chromatic mediants, and every other chord has major ones.
+    /// The mediants below are taken an octave higher if they would be below MIDI note 0.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let mediants = Chord::major(midi!(C, 4)).chromatic_mediants();
+    /// assert_eq!(
+    ///     mediants,
+    ///     [
+    ///         Chord::major(midi!(E, 4)),
+    ///         Chord::major(midi!(DSharp, 4)),
+    ///         Chord::major(midi!(A, 3)),
+    ///         Chord::major(midi!(GSharp, 3)),
+    ///     ]
+    /// );
+    ///
+    /// let mediants = Chord::major(midi!(C, -1)).chromatic_mediants();
+    /// assert_eq!(mediants[3], Chord::major(midi!(GSharp, -1)));
+    /// ```
+    pub fn chromatic_mediants(self) -> [Chord; 4] {
+        let root = self.root;
+        let triad = match TriadQuality::of(&self) {
+            TriadQuality::Minor => Self::minor,
+            _ => Self::major,
+        };
+        // Fall back to the octave above for roots in the lowest octave
+        let below = |interval: Interval| match root.into_byte().checked_sub(interval.semitones()) {
+            Some(byte) => MidiNote::from_byte(byte),
+            None => root + (Interval::OCTAVE - interval),
+        };
+
+        [
+            triad(root + Interval::MAJOR_THIRD),
+            triad(root + Interval::MINOR_THIRD),
+            triad(below(Interval::MINOR_THIRD)),
+            triad(below(Interval::MAJOR_THIRD)),
+        ]
+    }
+
+    /// Returns each way to hear this fully diminished seventh chord as the leading tone chord
+    /// of a major key, paired with the tonic it resolves to,
+    /// or `None` if this isn't a diminished seventh chord.
+    ///
+    /// Each note of the chord is taken as the root in turn, from the bass up.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let resolutions = Chord::diminished_seventh(midi!(B, 3)).diminished7_resolutions().unwrap();
+    ///
+    /// assert_eq!(
+    ///     resolutions[0],
+    ///     (Chord::diminished_seventh(midi!(B, 3)), Chord::major(midi!(C, 4)))
+    /// );
+    /// assert_eq!(
+    ///     resolutions[2],
+    ///     (Chord::diminished_seventh(midi!(F, 4)), Chord::major(midi!(FSharp, 4)))
+    /// );
+    ///
+    /// assert!(Chord::seventh(midi!(G, 3)).diminished7_resolutions().is_none());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn diminished7_resolutions(self) -> Option<Vec<(Chord, Chord)>> {
+        let root = self.root.pitch();
+        let diminished: PitchSet = [0, 3, 6, 9]
+            .into_iter()
+            .map(|semitones| root + Interval::new(semitones))
+            .collect();
+        if self.pitch_set() != diminished {
+            return None;
+        }
+
+        let resolutions = self
+            .into_iter()
+            .map(|note| {
+                (
+                    Self::diminished_seventh(note),
+                    Self::major(note + Interval::MINOR_SECOND),
+                )
+            })
+            .collect();
+        Some(resolutions)
+    }
+
+    /// Returns true if both chords are major or both are minor,
+    /// with roots a third apart and one pitch in common.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// assert!(chord.clone().is_chromatic_mediant_of(Chord::major(midi!(GSharp, 2))));
+    ///
+    /// // The diatonic mediant shares two pitches
+    /// assert!(!chord.is_chromatic_mediant_of(Chord::minor(midi!(E, 4))));
+    /// ```
+    pub fn is_chromatic_mediant_of(self, other: Self) -> bool {
+        let quality = TriadQuality::of(&self);
+        if quality != TriadQuality::of(&other)
+            || !matches!(quality, TriadQuality::Major | TriadQuality::Minor)
+        {
+            return false;
+        }
+
+        let is_third = matches!(
+            other.root.pitch() - self.root.pitch(),
+            Interval::MINOR_THIRD
+                | Interval::MAJOR_THIRD
+                | Interval::MINOR_SIXTH
+                | Interval::MAJOR_SIXTH
+        );
+        let common = self.pitch_set().bits & other.pitch_set().bits;
+        is_third && common.count_ones() == 1
+    }
+
+    /// Returns the diatonic triads of `scale` voiced above a `pedal` in the bass,
+    /// with how the pedal relates to each triad.
+    ///
+    /// The pedal is placed in the octave below the scale's first note.
+    /// ```
+    /// use staff::{midi, Chord, Pitch, Scale};
+    /// use staff::chord::PedalRelation;
+    ///
+    /// let harmonies = Chord::pedal_point_harmonies(Pitch::C, Scale::major(midi!(C, 4)));
+    ///
+    /// let (chord, relation) = &harmonies[4];
+    /// assert_eq!(chord.to_string(), "G/C");
+    /// assert_eq!(*relation, PedalRelation::Suspension);
+    ///
+    /// assert_eq!(harmonies[0].1, PedalRelation::ChordMember);
+    /// assert_eq!(harmonies[1].1, PedalRelation::NeighborTone);
+    ///
+    /// // The C pedal is more than a step from every note of D♯°
+    /// let harmonies = Chord::pedal_point_harmonies(Pitch::C, Scale::major(midi!(E, 4)));
+    /// assert_eq!(harmonies[6].1, PedalRelation::NonChordTone);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn pedal_point_harmonies<T, U>(
+        pedal: Pitch,
+        scale: Scale<T, U>,
+    ) -> Vec<(Chord, PedalRelation)>
+    where
+        Scale<T, U>: Iterator<Item = MidiNote> + Clone,
+    {
+        let Some(first) = scale.clone().next() else {
+            return Vec::new();
+        };
+        let below = match (first.pitch() - pedal).semitones() {
+            0 => Interval::OCTAVE.semitones(),
+            semitones => semitones,
+        };
+        let pedal_note = MidiNote::from_byte(first.into_byte().saturating_sub(below));
+
+        let len = scale.clone().count() as u8;
+        (1..=len)
+            .filter_map(|degree| scale.chord(degree, 3))
+            .map(|triad| {
+                let pitches = triad.pitch_set();
+                let steps = [Interval::MINOR_SECOND, Interval::MAJOR_SECOND];
+                let is_step_above = steps.into_iter().any(|step| pitches.contains(pedal - step));
+                let is_step_below = steps.into_iter().any(|step| pitches.contains(pedal + step));
+
+                let relation = if pitches.contains(pedal) {
+                    PedalRelation::ChordMember
+                } else if is_step_above {
+                    PedalRelation::Suspension
+                } else if is_step_below {
+                    PedalRelation::NeighborTone
+                } else {
+                    PedalRelation::NonChordTone
+                };
+                (triad.with_bass(pedal_note), relation)
+            })
+            .collect()
+    }
+
+    /// Returns the diatonic triads of `scale` that share at least two pitches with this chord,
+    /// along with those common tones, sorted by the number of common tones descending.
+    /// ```
+    /// use staff::{midi, Chord, Pitch, Scale};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// let chords = chord.reharmonize_with_scale(Scale::major(midi!(C, 4)));
+    ///
+    /// assert_eq!(
+    ///     chords,
+    ///     [
+    ///         (Chord::minor(midi!(E, 4)), vec![Pitch::E, Pitch::G]),
+    ///         (Chord::minor(midi!(A, 4)), vec![Pitch::C, Pitch::E]),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn reharmonize_with_scale<T, U>(self, scale: Scale<T, U>) -> Vec<(Chord, Vec<Pitch>)>
+    where
+        Scale<T, U>: Iterator<Item = MidiNote> + Clone,
+    {
+        let pitches = self.pitch_set();
+        let len = scale.clone().count() as u8;
+
+        let mut chords: Vec<_> = (1..=len)
+            .filter_map(|degree| scale.chord(degree, 3))
+            .filter(|chord| chord.pitch_set() != pitches)
+            .map(|chord| {
+                let common: Vec<_> = chord
+                    .pitch_set()
+                    .filter(|&pitch| pitches.contains(pitch))
+                    .collect();
+                (chord, common)
+            })
+            .filter(|(_, common)| common.len() >= 2)
+            .collect();
+
+        chords.sort_by_key(|(_, common)| core::cmp::Reverse(common.len()));
+        chords
+    }
+
+    /// Returns the staff position of each note in this chord, from the bass up.
+    /// ```
+    /// use staff::{midi, Chord, Clef};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// let positions = chord.to_staff_notation(Clef::Treble);
+    ///
+    /// let lines: Vec<_> = positions.iter().map(|pos| pos.line_or_space).collect();
+    /// assert_eq!(lines, [0, 2, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_staff_notation(self, clef: Clef) -> Vec<StaffPosition> {
+        self.into_iter()
+            .map(|midi| StaffPosition::new(midi, clef))
+            .collect()
+    }
+
+    /// Returns the notes of this chord from the bass up, spelled in thirds from a root
+    /// spelled as it appears in the major `key`.
+    /// ```
+    /// use staff::{midi, Chord, Key, Natural, Note, Pitch};
+    ///
+    /// let chord = Chord::major(midi!(GSharp, 3));
+    /// let notes = chord.respell_for_key(Key::major(Pitch::GSharp));
+    /// assert_eq!(notes, [Note::flat(Natural::A), Note::C, Note::flat(Natural::E)]);
+    ///
+    /// let chord = Chord::major(midi!(E, 3));
+    /// let notes = chord.respell_for_key(Key::major(Pitch::A));
+    /// assert_eq!(notes, [Note::E, Note::sharp(Natural::G), Note::B]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn respell_for_key(self, key: Key) -> Vec<Note> {
+        let root = Note::from_pitch_with(self.root.pitch(), AccidentalPreference::KeyBased(key));
+        self.spelled_from(root).map(|(_, note)| note).collect()
+    }
+
+    /// Returns each note of this chord with its spelling as a third, fifth, or other
+    /// stacked interval above `root`.
+    #[cfg(feature = "std")]
+    fn spelled_from(self, root: Note) -> impl Iterator<Item = (MidiNote, Note)> {
+        let root_pitch = self.root.pitch();
+        let has_fifth = self
+            .pitch_set()
+            .contains(root_pitch + Interval::PERFECT_FIFTH);
+
+        self.into_iter().map(move |midi| {
+            let pitch = midi.pitch();
+            let steps = match (pitch - root_pitch).semitones() {
+                0 => 0,
+                1 | 2 => 1,
+                3 | 4 => 2,
+                5 => 3,
+                6 if has_fifth => 3,
+                6 | 7 => 4,
+                8 if !has_fifth => 4,
+                8 | 9 => 5,
+                _ => 6,
+            };
+            let note = Note::from_pitch_with(pitch, AccidentalPreference::Sharps);
+            let note = note.respell_as(root.natural + steps).unwrap_or(note);
+            (midi, note)
+        })
+    }
+
+    /// Returns a fingering of this chord on a guitar with `tuning`, as the fret of each string
+    /// from the lowest string up, or `None` for a muted string.
+    ///
+    /// Fingerings play every pitch of the chord with its bass as the lowest note,
+    /// using at most four fingers (without a barre) within a span of four frets.
+    /// Fingerings lower on the neck are preferred, then ones with fewer muted strings.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::fretboard::GuitarTuning;
+    ///
+    /// let tab = Chord::major(midi!(C, 3)).to_guitar_tab(GuitarTuning::standard());
+    /// assert_eq!(tab, Some(vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]));
+    ///
+    /// let tab = Chord::major(midi!(G, 2)).to_guitar_tab(GuitarTuning::standard());
+    /// assert_eq!(tab, Some(vec![Some(3), Some(2), Some(0), Some(0), Some(0), Some(3)]));
+    /// ```
+    #[cfg(feature = "fretboard")]
+    pub fn to_guitar_tab(self, tuning: GuitarTuning) -> Option<Vec<Option<u8>>> {
+        const MAX_FRET: u8 = 12;
+        const MAX_SPAN: u8 = 3;
+        const FINGERS: usize = 4;
+
+        struct Search<'a> {
+            strings: &'a [MidiNote],
+            pitches: PitchSet,
+            bass: Pitch,
+            score: (u8, usize, usize),
+            best: Option<Vec<Option<u8>>>,
+        }
+
+        impl Search<'_> {
+            fn search(&mut self, frets: &mut Vec<Option<u8>>) {
+                let fretted = || frets.iter().flatten().copied().filter(|fret| *fret > 0);
+                if fretted().count() > FINGERS {
+                    return;
+                }
+                if let (Some(low), Some(high)) = (fretted().min(), fretted().max()) {
+                    if high - low > MAX_SPAN {
+                        return;
+                    }
+                }
+
+                let Some(&string) = self.strings.get(frets.len()) else {
+                    self.finish(frets);
+                    return;
+                };
+
+                for fret in (0..=MAX_FRET).map(Some).chain([None]) {
+                    let is_chord_tone = fret.is_none_or(|fret| {
+                        self.pitches
+                            .contains((string + Interval::new(fret)).pitch())
+                    });
+                    if is_chord_tone {
+                        frets.push(fret);
+                        self.search(frets);
+                        frets.pop();
+                    }
+                }
+            }
+
+            fn finish(&mut self, frets: &[Option<u8>]) {
+                let notes =
+                    self.strings.iter().zip(frets).filter_map(|(string, fret)| {
+                        fret.map(|fret| *string + Interval::new(fret))
+                    });
+                let played: PitchSet = notes.clone().map(|midi| midi.pitch()).collect();
+                let lowest = notes.min().map(|midi| midi.pitch());
+                if played != self.pitches || lowest != Some(self.bass) {
+                    return;
+                }
+
+                let muted = frets.iter().filter(|fret| fret.is_none()).count();
+                let highest = frets.iter().flatten().copied().max().unwrap_or(0);
+                let fingers = frets.iter().flatten().filter(|fret| **fret > 0).count();
+                let score = (highest, muted, fingers);
+                if self.best.is_none() || score < self.score {
+                    self.score = score;
+                    self.best = Some(frets.to_vec());
+                }
+            }
+        }
+
+        let mut search = Search {
+            strings: &tuning.strings,
+            pitches: self.pitch_set(),
+            bass: self.bass().pitch(),
+            score: (0, 0, 0),
+            best: None,
+        };
+        search.search(&mut Vec::with_capacity(tuning.strings.len()));
+        search.best
+    }
+
+    /// Returns this chord in LilyPond notation with the given duration, from the bass up.
+    ///
+    /// Notes are spelled from the root and written in absolute octaves, where `c'` is middle C.
+    /// Slash chords are written with their bass as the lowest note,
+    /// as LilyPond only supports the `/` bass syntax in chord mode.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::time::{Duration, DurationKind};
+    ///
+    /// let chord = Chord::major(midi!(C, 4));
+    /// assert_eq!(chord.to_lilypond_block(DurationKind::Quarter.into()), "<c' e' g'>4");
+    ///
+    /// let chord = Chord::minor(midi!(FSharp, 2));
+    /// let duration = Duration::new(DurationKind::Half, true);
+    /// assert_eq!(chord.to_lilypond_block(duration), "<fis, a, cis>2.");
+    ///
+    /// let chord = Chord::major(midi!(GSharp, 3)).first_inversion();
+    /// assert_eq!(chord.to_lilypond_block(DurationKind::Whole.into()), "<c' ees' aes'>1");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_lilypond_block(self, duration: Duration) -> String {
+        let root = self.root.pitch();
+        let root_note =
+            Note::from_pitch_with(root, AccidentalPreference::KeyBased(Key::major(root)));
+        let notes: Vec<_> = self
+            .spelled_from(root_note)
+            .map(|(midi, note)| lilypond_note(midi, note))
+            .collect();
+
+        let value = match duration.kind {
+            DurationKind::Eigth => 8,
+            DurationKind::Quarter => 4,
+            DurationKind::Half => 2,
+            DurationKind::Whole => 1,
+        };
+        let dot = if duration.is_dotted { "." } else { "" };
+
+        format!("<{}>{}{}", notes.join(" "), value, dot)
+    }
+}
+
+#[cfg(feature = "std")]
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Returns `note` as a LilyPond note name with its octave marks, sounding at `midi`.
+#[cfg(feature = "std")]
+fn lilypond_note(midi: MidiNote, note: Note) -> String {
+    let mut s = String::new();
+    s.push((b'a' + note.natural as u8) as char);
+    s.push_str(match note.accidental {
+        Accidental::Natural => "",
+        Accidental::Flat => "es",
+        Accidental::DoubleFlat => "eses",
+        Accidental::Sharp => "is",
+        Accidental::DoubleSharp => "isis",
+    });
+
+    // The octave of the written natural, which can differ from the sounding octave (e.g. B#3 is C4)
+    let alteration: i16 = match note.accidental {
+        Accidental::Natural => 0,
+        Accidental::Flat => -1,
+        Accidental::DoubleFlat => -2,
+        Accidental::Sharp => 1,
+        Accidental::DoubleSharp => 2,
+    };
+    let natural = Pitch::from(note.natural).into_byte() as i16;
+    let octave = (midi.into_byte() as i16 - alteration - natural).div_euclid(12) - 1;
+
+    // LilyPond's unmarked octave starts at C3
+    let marks = octave - 3;
+    let mark = if marks > 0 { '\'' } else { ',' };
+    for _ in 0..marks.abs() {
+        s.push(mark);
+    }
+    s
+}
+
+impl FromIterator<MidiNote> for Chord {
+    fn from_iter<T: IntoIterator<Item = MidiNote>>(iter: T) -> Self {
+        let mut notes = iter.into_iter();
+        let root = notes.next().unwrap_or(MidiNote::from_byte(0));
+
+        Self::from_midi(root, core::iter::once(root).chain(notes)).unwrap()
+    }
+}
+
+impl IntoIterator for Chord {
+    type Item = MidiNote;
+
+    type IntoIter = MidiNotes;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.into_fmt().fmt(f)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let natural: Natural = chars.next().unwrap().try_into().unwrap();
+
+        let mut next = chars.next();
+        let root: Pitch = match next {
+            Some('b') => {
+                next = chars.next();
+                if next == Some('b') {
+                    next = chars.next();
+                    Note::double_flat(natural).into()
+                } else {
+                    Note::flat(natural).into()
+                }
+            }
+            Some('#') => {
+                next = chars.next();
+                if next == Some('#') {
+                    next = chars.next();
+                    Note::double_sharp(natural).into()
+                } else {
+                    Note::sharp(natural).into()
+                }
+            }
+            _ => natural.into(),
+        };
+
+        let mut chord = match next {
+            Some('m') => {
+                next = chars.next();
+                Chord::minor(MidiNote::new(root, Octave::FOUR))
+            }
+            _ => Chord::major(MidiNote::new(root, Octave::FOUR)),
+        };
+
+        loop {
+            if let Some(c) = next {
+                match c {
+                    'b' => match chars.next() {
+                        Some(c) => match c {
+                            '5' => chord.intervals.push(Interval::TRITONE),
+                            _ => todo!(),
+                        },
+                        None => break,
+                    },
+                    '7' => chord.intervals.push(Interval::MINOR_SEVENTH),
+                    _ => todo!(),
+                }
+                next = chars.next();
+            } else {
+                break;
+            }
+        }
+
+        Ok(chord)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An interval that repeats a pitch already in the chord
+    DuplicatePitch(Pitch),
+    /// An index outside of the notes in the chord
+    InvalidVoice(usize),
+    /// An index of a note that was already used
+    DuplicateVoice(usize),
+    /// Notes spread over more intervals than a chord can hold
+    TooWide,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicatePitch(pitch) => write!(f, "Duplicate pitch `{}`", pitch),
+            Self::InvalidVoice(idx) => write!(f, "Invalid voice index `{}`", idx),
+            Self::DuplicateVoice(idx) => write!(f, "Duplicate voice index `{}`", idx),
+            Self::TooWide => f.write_str("Chord spans too many octaves"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        midi::{MidiNote, Octave},
+        scale, Chord, Pitch, Scale,
+    };
+
+    #[test]
+    fn it_parses_d_double_sharp_major() {
         let chord: Chord = "D##".parse().unwrap();
         assert_eq!(chord, Chord::major(MidiNote::new(Pitch::E, Octave::FOUR)));
     }
@@ -311,6 +2578,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_equals_first_inversion_enharmonically() {
+        let chord = Chord::major(MidiNote::new(Pitch::C, Octave::FOUR));
+        let inversion = Chord::from_midi(
+            MidiNote::new(Pitch::C, Octave::FIVE),
+            [
+                MidiNote::new(Pitch::E, Octave::FOUR),
+                MidiNote::new(Pitch::G, Octave::FOUR),
+                MidiNote::new(Pitch::C, Octave::FIVE),
+            ],
+        )
+        .unwrap();
+
+        assert!(chord.equals_enharmonically(inversion));
+    }
+
+    #[test]
+    fn it_equals_e_sharp_major_enharmonically() {
+        let e_sharp: Chord = "E#".parse().unwrap();
+        let f: Chord = "F".parse().unwrap();
+        assert!(e_sharp.equals_enharmonically(f));
+
+        let e: Chord = "E".parse().unwrap();
+        assert!(!e.equals_enharmonically("F".parse().unwrap()));
+    }
+
+    #[test]
+    fn it_rejects_out_of_range_scale_degrees() {
+        let scale = Scale::major(MidiNote::new(Pitch::C, Octave::FOUR));
+        assert_eq!(
+            Chord::from_scale_degrees(scale, &[1, 3, 8]),
+            Err(scale::Error::InvalidDegree(8))
+        );
+    }
+
     #[test]
     fn it_collects_from_maj_13th() {
         let chord = Chord::from_midi(
@@ -0,0 +1,13 @@
+/// How a sustained pedal tone relates to a chord sounding above it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PedalRelation {
+    /// The pedal is a note of the chord.
+    ChordMember,
+    /// The pedal is a step above a chord tone, like a suspension waiting to resolve down.
+    Suspension,
+    /// The pedal is a step below a chord tone.
+    NeighborTone,
+    /// The pedal is more than a step from every chord tone.
+    NonChordTone,
+}
@@ -0,0 +1,37 @@
+use super::Chord;
+use crate::set::PitchSet;
+
+/// Two chords played at the same time, such as a D major triad over a C major triad.
+///
+/// Created with [`Chord::polychord`].
+/// ```
+/// use staff::{midi, Chord, Pitch};
+///
+/// let poly = Chord::polychord(Chord::major(midi!(D, 5)), Chord::major(midi!(C, 4)));
+/// assert_eq!(poly.clone().display_symbol(), "D/C");
+///
+/// let pitches: Vec<Pitch> = poly.as_pitch_class_set().collect();
+/// assert_eq!(pitches, [Pitch::C, Pitch::D, Pitch::E, Pitch::FSharp, Pitch::G, Pitch::A]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolyChord {
+    pub upper: Chord,
+    pub lower: Chord,
+}
+
+impl PolyChord {
+    /// Returns the pitches of both chords.
+    pub fn as_pitch_class_set(self) -> PitchSet {
+        self.upper
+            .pitch_set()
+            .chain(self.lower.pitch_set())
+            .collect()
+    }
+
+    /// Returns the symbol of the upper chord over the lower chord, like `D/C`.
+    #[cfg(feature = "std")]
+    pub fn display_symbol(self) -> String {
+        format!("{}/{}", self.upper, self.lower)
+    }
+}
@@ -0,0 +1,188 @@
+use super::{RomanNumeral, TriadQuality};
+use crate::{set::PitchSet, Chord, Interval, Key, SignedInterval};
+
+/// Harmonic function of a chord in a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HarmonicEvent {
+    /// A chord built from the notes of the key
+    Diatonic(RomanNumeral),
+    /// A chord borrowed from the key with the same tonic in another mode
+    Borrowed(RomanNumeral, Key),
+    /// A major or dominant seventh chord that tonicizes a diatonic chord other than the tonic
+    SecondaryDominant { of: RomanNumeral },
+    /// Any other chord
+    Chromatic,
+}
+
+/// A sequence of chords.
+/// ```
+/// use staff::{midi, Chord, Key, Pitch};
+/// use staff::chord::{ChordProgression, HarmonicEvent, RomanNumeral};
+///
+/// let progression = ChordProgression::new(vec![
+///     Chord::major(midi!(C, 4)),
+///     Chord::seventh(midi!(A, 3)),
+///     Chord::minor(midi!(D, 4)),
+///     Chord::seventh(midi!(G, 3)),
+///     Chord::major(midi!(GSharp, 3)),
+/// ]);
+///
+/// let events = progression.analyze(Key::major(Pitch::C));
+///
+/// assert_eq!(events[0], HarmonicEvent::Diatonic(RomanNumeral::diatonic(1)));
+/// assert_eq!(events[1], HarmonicEvent::SecondaryDominant { of: RomanNumeral::diatonic(2) });
+/// assert_eq!(events[3], HarmonicEvent::Diatonic(RomanNumeral::diatonic(5)));
+///
+/// let HarmonicEvent::Borrowed(numeral, key) = events[4] else { panic!() };
+/// assert_eq!(numeral.to_string(), "♭VI");
+/// assert_eq!(key.to_string(), "bbb");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChordProgression {
+    pub chords: Vec<Chord>,
+}
+
+impl ChordProgression {
+    pub fn new(chords: Vec<Chord>) -> Self {
+        Self { chords }
+    }
+
+    /// Returns the harmonic function of each chord in the major `key`.
+    ///
+    /// Borrowed chords are taken from the parallel natural minor.
+    pub fn analyze(self, key: Key) -> Vec<HarmonicEvent> {
+        let tonic = key.tonic();
+        let minor = Key::major(tonic + Interval::MINOR_THIRD);
+        let is_diatonic = |mut pitches: PitchSet, key: Key| {
+            pitches.all(|pitch| pitch.diatonic_index(key).is_some())
+        };
+
+        self.chords
+            .iter()
+            .map(|chord| {
+                let pitches = chord.pitch_set();
+                let numeral = RomanNumeral::from_chord(chord, tonic);
+
+                if is_diatonic(pitches, key) {
+                    HarmonicEvent::Diatonic(numeral)
+                } else if let Some(of) = secondary_dominant_target(chord, pitches, key) {
+                    HarmonicEvent::SecondaryDominant { of }
+                } else if is_diatonic(pitches, minor) {
+                    HarmonicEvent::Borrowed(numeral, minor)
+                } else {
+                    HarmonicEvent::Chromatic
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Chord> for ChordProgression {
+    fn from_iter<T: IntoIterator<Item = Chord>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Common patterns in the motion of the bass through a chord progression.
+/// ```
+/// use staff::{midi, Chord};
+/// use staff::chord::BassLineAnalysis;
+///
+/// // I IV viiø7 iii vi ii V I
+/// let chords = [
+///     Chord::major(midi!(C, 3)),
+///     Chord::major(midi!(F, 3)),
+///     Chord::half_diminished(midi!(B, 2)),
+///     Chord::minor(midi!(E, 3)),
+///     Chord::minor(midi!(A, 2)),
+///     Chord::minor(midi!(D, 3)),
+///     Chord::major(midi!(G, 2)),
+///     Chord::major(midi!(C, 3)),
+/// ];
+/// let analysis = BassLineAnalysis::new(&chords);
+///
+/// assert_eq!(analysis.motions.len(), 7);
+/// assert!(analysis.has_falling_fifths);
+/// assert_eq!(analysis.cliche_patterns, ["circle of fifths"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BassLineAnalysis {
+    /// The interval from each bass note to the next
+    pub motions: Vec<SignedInterval>,
+    /// True if the bass moves down a fifth (or up a fourth) three times in a row
+    pub has_falling_fifths: bool,
+    /// True if the bass moves up by step twice in a row
+    pub has_ascending_steps: bool,
+    /// The names of the common bass line patterns found in the progression
+    pub cliche_patterns: Vec<&'static str>,
+}
+
+impl BassLineAnalysis {
+    pub fn new(chords: &[Chord]) -> Self {
+        let motions = Chord::bass_line_motion(chords);
+        let has_run = |len: usize, f: fn(SignedInterval) -> bool| {
+            motions
+                .windows(len)
+                .any(|window| window.iter().copied().all(f))
+        };
+
+        // Diatonic fifths include the tritone between the fourth and seventh degrees
+        let has_falling_fifths =
+            has_run(3, |motion| matches!(motion.semitones.rem_euclid(12), 5 | 6));
+        let has_ascending_steps = has_run(2, |motion| matches!(motion.semitones, 1 | 2));
+
+        let mut cliche_patterns = Vec::new();
+        if has_falling_fifths {
+            cliche_patterns.push("circle of fifths");
+        }
+        if has_run(3, |motion| motion.semitones == -1) {
+            cliche_patterns.push("chromatic descent");
+        }
+        let is_lament = motions.windows(4).any(|window| {
+            window
+                .iter()
+                .all(|motion| matches!(motion.semitones, -2 | -1))
+                && window.iter().map(|motion| motion.semitones).sum::<i32>() == -5
+        });
+        if is_lament {
+            cliche_patterns.push("lament");
+        }
+
+        Self {
+            motions,
+            has_falling_fifths,
+            has_ascending_steps,
+            cliche_patterns,
+        }
+    }
+}
+
+/// Returns the diatonic triad tonicized by `chord` if it is a major or dominant seventh chord
+/// a fifth above that triad's root.
+fn secondary_dominant_target(chord: &Chord, pitches: PitchSet, key: Key) -> Option<RomanNumeral> {
+    let root = chord.root.pitch();
+    let dominant: PitchSet = [
+        Interval::UNISON,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SEVENTH,
+    ]
+    .into_iter()
+    .map(|interval| root + interval)
+    .collect();
+
+    if TriadQuality::of(chord) != TriadQuality::Major || pitches.bits & !dominant.bits != 0 {
+        return None;
+    }
+
+    let target = root + Interval::PERFECT_FOURTH;
+    let degree = target.diatonic_index(key)? + 1;
+    let numeral = RomanNumeral::diatonic(degree);
+    if degree == 1 || numeral.quality == TriadQuality::Diminished {
+        return None;
+    }
+
+    Some(numeral)
+}
@@ -0,0 +1,148 @@
+use crate::{note::Accidental, scale::ScaleIntervals, Chord, Interval, Pitch};
+use core::fmt::{self, Write};
+
+/// Quality of the triad built on a chord's root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriadQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+}
+
+impl TriadQuality {
+    /// Returns the quality of the triad in `chord`, treating chords without a third as major.
+    /// ```
+    /// use staff::{midi, Chord};
+    /// use staff::chord::TriadQuality;
+    ///
+    /// let chord = Chord::half_diminished(midi!(B, 3));
+    /// assert_eq!(TriadQuality::of(&chord), TriadQuality::Diminished);
+    /// ```
+    pub fn of(chord: &Chord) -> Self {
+        let pitches = chord.pitch_set();
+        let root = chord.root.pitch();
+        let has = |interval| pitches.contains(root + interval);
+
+        if has(Interval::MAJOR_THIRD) {
+            if has(Interval::MINOR_SIXTH) && !has(Interval::PERFECT_FIFTH) {
+                Self::Augmented
+            } else {
+                Self::Major
+            }
+        } else if has(Interval::MINOR_THIRD) {
+            if has(Interval::TRITONE) && !has(Interval::PERFECT_FIFTH) {
+                Self::Diminished
+            } else {
+                Self::Minor
+            }
+        } else {
+            Self::Major
+        }
+    }
+}
+
+/// Roman numeral for the scale degree and quality of a chord in a key.
+/// ```
+/// use staff::chord::{RomanNumeral, TriadQuality};
+/// use staff::note::Accidental;
+///
+/// let numeral = RomanNumeral::new(6, Accidental::Flat, TriadQuality::Major);
+/// assert_eq!(numeral.to_string(), "♭VI");
+///
+/// assert_eq!(RomanNumeral::diatonic(7).to_string(), "vii°");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomanNumeral {
+    /// The 1-indexed scale degree of the chord's root
+    pub degree: u8,
+    /// The alteration of the root from the major scale
+    pub accidental: Accidental,
+    pub quality: TriadQuality,
+}
+
+impl RomanNumeral {
+    pub const fn new(degree: u8, accidental: Accidental, quality: TriadQuality) -> Self {
+        Self {
+            degree,
+            accidental,
+            quality,
+        }
+    }
+
+    /// Returns the numeral of the diatonic triad on the 1-indexed `degree` of a major key.
+    pub const fn diatonic(degree: u8) -> Self {
+        let quality = match degree {
+            2 | 3 | 6 => TriadQuality::Minor,
+            7 => TriadQuality::Diminished,
+            _ => TriadQuality::Major,
+        };
+        Self::new(degree, Accidental::Natural, quality)
+    }
+
+    /// Returns the numeral of `chord` in the major key of `tonic`,
+    /// spelling chromatic roots as a flattened degree.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    /// use staff::chord::RomanNumeral;
+    ///
+    /// let numeral = RomanNumeral::from_chord(&Chord::minor(midi!(E, 4)), Pitch::C);
+    /// assert_eq!(numeral.to_string(), "iii");
+    ///
+    /// let numeral = RomanNumeral::from_chord(&Chord::major(midi!(ASharp, 3)), Pitch::C);
+    /// assert_eq!(numeral.to_string(), "♭VII");
+    /// ```
+    pub fn from_chord(chord: &Chord, tonic: Pitch) -> Self {
+        let interval = chord.root.pitch() - tonic;
+        let degree = |interval| {
+            ScaleIntervals::major()
+                .into_iter()
+                .position(|degree| degree == interval)
+                .map(|idx| idx as u8 + 1)
+        };
+
+        let (degree, accidental) = match degree(interval) {
+            Some(degree) => (degree, Accidental::Natural),
+            None => {
+                let flat = (interval.semitones() + 1) % Interval::OCTAVE.semitones();
+                (degree(Interval::new(flat)).unwrap(), Accidental::Flat)
+            }
+        };
+
+        Self::new(degree, accidental, TriadQuality::of(chord))
+    }
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.accidental.is_natural() {
+            self.accidental.fmt(f)?;
+        }
+
+        let numeral = match self.degree {
+            1 => "I",
+            2 => "II",
+            3 => "III",
+            4 => "IV",
+            5 => "V",
+            6 => "VI",
+            _ => "VII",
+        };
+        match self.quality {
+            TriadQuality::Major | TriadQuality::Augmented => f.write_str(numeral)?,
+            TriadQuality::Minor | TriadQuality::Diminished => {
+                for c in numeral.chars() {
+                    f.write_char(c.to_ascii_lowercase())?;
+                }
+            }
+        }
+
+        match self.quality {
+            TriadQuality::Diminished => f.write_char('°'),
+            TriadQuality::Augmented => f.write_char('+'),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::midi::MidiNote;
+use core::fmt;
+
+/// Four-part voicing of a chord for soprano, alto, tenor, and bass voices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SatbVoicing {
+    pub soprano: MidiNote,
+    pub alto: MidiNote,
+    pub tenor: MidiNote,
+    pub bass: MidiNote,
+}
+
+impl IntoIterator for SatbVoicing {
+    type Item = MidiNote;
+
+    type IntoIter = core::array::IntoIter<MidiNote, 4>;
+
+    /// Returns an iterator over the voices from the bass up.
+    fn into_iter(self) -> Self::IntoIter {
+        [self.bass, self.tenor, self.alto, self.soprano].into_iter()
+    }
+}
+
+impl fmt::Display for SatbVoicing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "S: {}, A: {}, T: {}, B: {}",
+            self.soprano, self.alto, self.tenor, self.bass
+        )
+    }
+}
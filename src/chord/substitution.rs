@@ -0,0 +1,16 @@
+/// Reason a chord can replace another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubstitutionType {
+    /// The relative major or minor shares two of the chord's three notes.
+    Relative,
+    /// The parallel major or minor keeps the root and fifth while changing the mode.
+    Parallel,
+    /// A dominant seventh a tritone away shares the same tritone between its third and seventh.
+    Tritone,
+    /// A major sixth chord on the third of a minor seventh chord has exactly the same notes.
+    AddedSixth,
+    /// The tonic can be replaced by ♭VI when resolving from the dominant,
+    /// as well as by vi which is listed as its relative.
+    Deceptive,
+}
@@ -0,0 +1,64 @@
+use super::Chord;
+use crate::{midi::MidiNote, Interval};
+
+/// A triad voiced above a lower chord, such as a D major triad over G7.
+///
+/// Created with [`Chord::with_upper_structure`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpperStructureChord {
+    lower: Chord,
+    triad: Chord,
+}
+
+impl UpperStructureChord {
+    /// Creates a voicing of `triad` moved to the octave above the bass of `lower`.
+    pub(super) fn new(lower: Chord, triad: Chord) -> Self {
+        let bass = lower.bass();
+        let target = bass + Interval::OCTAVE + (triad.bass().pitch() - bass.pitch());
+        let shift = target.into_byte() as i16 - triad.bass().into_byte() as i16;
+        let transpose =
+            |midi: MidiNote| MidiNote::from_byte((midi.into_byte() as i16 + shift) as u8);
+
+        let triad = Chord {
+            root: transpose(triad.root),
+            bass: triad.bass.map(transpose),
+            ..triad
+        };
+        Self { lower, triad }
+    }
+
+    /// Returns the lower chord and the upper triad of this voicing.
+    pub fn analyze(self) -> (Chord, Chord) {
+        (self.lower, self.triad)
+    }
+
+    /// Returns a single chord with the notes of both the lower chord and the upper triad.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let voicing = Chord::seventh(midi!(G, 3)).with_upper_structure(Chord::major(midi!(D, 4)));
+    ///
+    /// let notes = [
+    ///     midi!(G, 3), midi!(B, 3), midi!(D, 4), midi!(F, 4),
+    ///     midi!(D, 5), midi!(FSharp, 5), midi!(A, 5),
+    /// ];
+    /// assert!(voicing.to_chord().into_iter().eq(notes));
+    /// ```
+    pub fn to_chord(self) -> Chord {
+        let notes = self.lower.clone().into_iter().chain(self.triad);
+        Chord::from_midi(self.lower.root, notes).unwrap()
+    }
+
+    /// Returns the jazz shorthand for this voicing, like `G7 US(D)`.
+    /// ```
+    /// use staff::{midi, Chord};
+    ///
+    /// let voicing = Chord::seventh(midi!(G, 3)).with_upper_structure(Chord::major(midi!(D, 4)));
+    /// assert_eq!(voicing.label(), "G7 US(D)");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn label(self) -> String {
+        format!("{} US({})", self.lower, self.triad)
+    }
+}
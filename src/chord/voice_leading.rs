@@ -0,0 +1,168 @@
+use crate::{midi::MidiNote, set::PitchSet, Chord, Interval};
+use core::ops::RangeInclusive;
+
+/// A note of one part that is below a note of a lower part.
+///
+/// Voices are numbered from the bass up in each part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoiceCrossing {
+    pub voice: u8,
+    pub crossing_with: u8,
+    pub by_semitones: u8,
+}
+
+/// Two voices moving from one perfect fifth or octave to another of the same kind.
+///
+/// Voices are numbered from the bass up in each part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParallelMotion {
+    pub voice: u8,
+    pub with_voice: u8,
+    /// Either [`Interval::PERFECT_FIFTH`] or [`Interval::OCTAVE`], for octaves and unisons.
+    pub interval: Interval,
+}
+
+/// Solver for the smoothest movement of voices from one chord to the next.
+///
+/// Voices are assigned the notes of the next chord with the least total motion in semitones,
+/// while keeping each voice in its range.
+/// ```
+/// use staff::{midi, Chord};
+/// use staff::chord::VoiceLeadingSolver;
+///
+/// let from = [midi!(C, 3), midi!(G, 3), midi!(E, 4), midi!(C, 5)];
+/// let ranges = [
+///     midi!(E, 2)..=midi!(C, 4),
+///     midi!(C, 3)..=midi!(G, 4),
+///     midi!(G, 3)..=midi!(D, 5),
+///     midi!(C, 4)..=midi!(G, 5),
+/// ];
+///
+/// let voices = VoiceLeadingSolver::new()
+///     .solve(&from, Chord::major(midi!(F, 3)), &ranges)
+///     .unwrap();
+///
+/// assert_eq!(voices, [midi!(C, 3), midi!(A, 3), midi!(F, 4), midi!(C, 5)]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoiceLeadingSolver {
+    /// Reject voicings with parallel fifths or octaves.
+    pub avoid_parallels: bool,
+}
+
+impl Default for VoiceLeadingSolver {
+    fn default() -> Self {
+        Self {
+            avoid_parallels: true,
+        }
+    }
+}
+
+impl VoiceLeadingSolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn avoid_parallels(mut self, avoid_parallels: bool) -> Self {
+        self.avoid_parallels = avoid_parallels;
+        self
+    }
+
+    /// Returns the voicing of `to` with the lowest total motion from `from`,
+    /// or `None` if no voicing fits in `voice_ranges`.
+    pub fn solve(
+        &self,
+        from: &[MidiNote],
+        to: Chord,
+        voice_ranges: &[RangeInclusive<MidiNote>],
+    ) -> Option<Vec<MidiNote>> {
+        if from.len() != voice_ranges.len() {
+            return None;
+        }
+
+        let pitches = to.pitch_set();
+        let required = pitches.count().min(from.len());
+
+        let candidates: Vec<Vec<MidiNote>> = from
+            .iter()
+            .zip(voice_ranges)
+            .map(|(voice, range)| {
+                let mut notes: Vec<_> = (range.start().into_byte()..=range.end().into_byte())
+                    .map(MidiNote::from_byte)
+                    .filter(|midi| pitches.contains(midi.pitch()))
+                    .collect();
+                notes.sort_by_key(|midi| midi.into_byte().abs_diff(voice.into_byte()));
+                notes
+            })
+            .collect();
+
+        let mut search = Search {
+            solver: self,
+            from,
+            candidates: &candidates,
+            required,
+            voices: Vec::with_capacity(from.len()),
+            best: None,
+        };
+        search.next(0);
+
+        search.best.map(|(_, voices)| voices)
+    }
+}
+
+struct Search<'a> {
+    solver: &'a VoiceLeadingSolver,
+    from: &'a [MidiNote],
+    candidates: &'a [Vec<MidiNote>],
+    required: usize,
+    voices: Vec<MidiNote>,
+    best: Option<(u32, Vec<MidiNote>)>,
+}
+
+impl Search<'_> {
+    fn next(&mut self, cost: u32) {
+        if let Some((best_cost, _)) = self.best {
+            if cost >= best_cost {
+                return;
+            }
+        }
+
+        let idx = self.voices.len();
+        if idx == self.from.len() {
+            let covered: PitchSet = self.voices.iter().map(|midi| midi.pitch()).collect();
+            if covered.count() >= self.required {
+                self.best = Some((cost, self.voices.clone()));
+            }
+            return;
+        }
+
+        for &midi in &self.candidates[idx] {
+            if self.solver.avoid_parallels && self.is_parallel(idx, midi) {
+                continue;
+            }
+
+            let motion = midi.into_byte().abs_diff(self.from[idx].into_byte());
+            self.voices.push(midi);
+            self.next(cost + motion as u32);
+            self.voices.pop();
+        }
+    }
+
+    /// Returns true if moving voice `idx` to `midi` forms parallel fifths or octaves with a lower voice.
+    fn is_parallel(&self, idx: usize, midi: MidiNote) -> bool {
+        let class = |a: MidiNote, b: MidiNote| {
+            Interval::new(a.into_byte().abs_diff(b.into_byte()) % Interval::OCTAVE.semitones())
+        };
+
+        self.voices.iter().zip(self.from).any(|(&voice, &prev)| {
+            let before = class(prev, self.from[idx]);
+            let after = class(voice, midi);
+            before == after
+                && prev != voice
+                && self.from[idx] != midi
+                && (after == Interval::PERFECT_FIFTH || after == Interval::UNISON)
+        })
+    }
+}
@@ -0,0 +1,114 @@
+//! Clefs and staff positions
+
+use crate::{
+    midi::MidiNote,
+    note::{Accidental, AccidentalPreference},
+    Note,
+};
+
+/// A clef that sets the pitches of the lines on a staff.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Clef {
+    Treble,
+    Bass,
+    Alto,
+    Tenor,
+    Soprano,
+    MezzoSoprano,
+    Baritone,
+}
+
+impl Clef {
+    /// Returns the position of middle C on this clef's staff.
+    /// ```
+    /// use staff::Clef;
+    ///
+    /// assert_eq!(Clef::Treble.middle_c(), 0);
+    /// assert_eq!(Clef::Bass.middle_c(), 12);
+    /// ```
+    pub const fn middle_c(self) -> i8 {
+        match self {
+            Self::Treble => 0,
+            Self::Soprano => 2,
+            Self::MezzoSoprano => 4,
+            Self::Alto => 6,
+            Self::Tenor => 8,
+            Self::Baritone => 10,
+            Self::Bass => 12,
+        }
+    }
+
+    /// Returns the staff line of middle C, counting up from 1 at the bottom line.
+    ///
+    /// Middle C sits on a ledger line for treble clef (line 0) and bass clef (line 6).
+    /// ```
+    /// use staff::Clef;
+    ///
+    /// assert_eq!(Clef::Treble.middle_c_line(), 0);
+    /// assert_eq!(Clef::Alto.middle_c_line(), 3);
+    /// assert_eq!(Clef::Soprano.middle_c_line(), 1);
+    /// ```
+    pub const fn middle_c_line(self) -> i8 {
+        self.middle_c() / 2
+    }
+}
+
+/// The vertical position of a note on a staff.
+///
+/// Positions count each line and space, with middle C at 0 in treble clef.
+/// The five lines of the staff are at positions 2, 4, 6, 8, and 10 for every clef,
+/// so notes outside of that range are drawn on ledger lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StaffPosition {
+    pub line_or_space: i8,
+    pub accidental: Option<Accidental>,
+}
+
+impl StaffPosition {
+    /// Returns the position of `midi` on the given clef's staff, spelling black keys with sharps.
+    /// ```
+    /// use staff::{midi, Clef};
+    /// use staff::clef::StaffPosition;
+    /// use staff::note::Accidental;
+    ///
+    /// let position = StaffPosition::new(midi!(FSharp, 5), Clef::Treble);
+    /// assert_eq!(position.line_or_space, 10);
+    /// assert_eq!(position.accidental, Some(Accidental::Sharp));
+    /// ```
+    pub fn new(midi: MidiNote, clef: Clef) -> Self {
+        let note = Note::from_pitch_with(midi.pitch(), AccidentalPreference::Sharps);
+        let natural = note.natural;
+        let accidental = Some(note.accidental).filter(|acc| !acc.is_natural());
+
+        // Steps from C in the same octave
+        let step = (natural as i8 + 5) % 7;
+        let line_or_space = (midi.octave().into_i8() - 4) * 7 + step + clef.middle_c();
+
+        Self {
+            line_or_space,
+            accidental,
+        }
+    }
+
+    /// Returns the number of ledger lines needed to draw a note at this position.
+    /// ```
+    /// use staff::{midi, Clef};
+    /// use staff::clef::StaffPosition;
+    ///
+    /// assert_eq!(StaffPosition::new(midi!(C, 4), Clef::Treble).ledger_lines(), 1);
+    /// assert_eq!(StaffPosition::new(midi!(B, 3), Clef::Treble).ledger_lines(), 1);
+    /// assert_eq!(StaffPosition::new(midi!(C, 6), Clef::Treble).ledger_lines(), 2);
+    /// assert_eq!(StaffPosition::new(midi!(C, 4), Clef::Alto).ledger_lines(), 0);
+    /// ```
+    pub const fn ledger_lines(self) -> u8 {
+        if self.line_or_space < 2 {
+            ((2 - self.line_or_space) / 2) as u8
+        } else if self.line_or_space > 10 {
+            ((self.line_or_space - 10) / 2) as u8
+        } else {
+            0
+        }
+    }
+}
@@ -67,6 +67,7 @@ impl Format for Chord {
 
         let mut intervals: IntervalSet = self.clone().intervals().collect();
 
+        let has_third = intervals.contains(Interval::MAJOR_THIRD);
         if intervals.maybe_remove(Interval::MAJOR_THIRD).is_none() {
             if intervals.maybe_remove(Interval::MINOR_THIRD).is_some() {
                 f.write_char('m')?
@@ -114,6 +115,17 @@ impl Format for Chord {
             }
         }
 
+        if !has_fifth && has_third && intervals.maybe_remove(Interval::MINOR_SIXTH).is_some() {
+            f.write_str("#5")?;
+            has_fifth = true;
+        }
+
+        for (semitones, alteration) in [(13, "b9"), (15, "#9"), (18, "#11"), (20, "b13")] {
+            if intervals.maybe_remove(Interval::new(semitones)).is_some() {
+                f.write_str(alteration)?;
+            }
+        }
+
         if let Some(bass) = self.bass {
             f.write_char('/')?;
             bass.fmt_with_octave(f, show_octave)?;
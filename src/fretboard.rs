@@ -57,3 +57,35 @@ where
         None
     }
 }
+
+/// The open strings of a guitar, from the lowest string to the highest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuitarTuning {
+    pub strings: Vec<MidiNote>,
+}
+
+impl GuitarTuning {
+    pub fn new(strings: Vec<MidiNote>) -> Self {
+        Self { strings }
+    }
+
+    /// Standard tuning of a six string guitar, E2 A2 D3 G3 B3 E4.
+    /// ```
+    /// use staff::midi;
+    /// use staff::fretboard::GuitarTuning;
+    ///
+    /// let tuning = GuitarTuning::standard();
+    /// assert_eq!(tuning.strings[0], midi!(E, 2));
+    /// assert_eq!(tuning.strings[5], midi!(E, 4));
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(vec![
+            MidiNote::new(Pitch::E, Octave::TWO),
+            MidiNote::new(Pitch::A, Octave::TWO),
+            MidiNote::new(Pitch::D, Octave::THREE),
+            MidiNote::new(Pitch::G, Octave::THREE),
+            MidiNote::new(Pitch::B, Octave::THREE),
+            MidiNote::new(Pitch::E, Octave::FOUR),
+        ])
+    }
+}
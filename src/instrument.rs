@@ -0,0 +1,89 @@
+//! Instruments and their ranges
+
+use crate::midi::MidiNote;
+use core::{fmt, ops::RangeInclusive};
+
+/// An instrument or voice with a standard playable range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Instrument {
+    Piano,
+    Guitar,
+    Violin,
+    Viola,
+    Cello,
+    Trumpet,
+    Trombone,
+    Soprano,
+    Alto,
+    Tenor,
+    Bass,
+}
+
+impl Instrument {
+    /// Returns the lowest sounding note of this instrument.
+    /// ```
+    /// use staff::{midi, Instrument};
+    ///
+    /// assert_eq!(Instrument::Violin.low(), midi!(G, 3));
+    /// ```
+    pub const fn low(self) -> MidiNote {
+        let byte = match self {
+            Self::Piano => 21,
+            Self::Guitar | Self::Trombone | Self::Bass => 40,
+            Self::Violin => 55,
+            Self::Viola | Self::Tenor => 48,
+            Self::Cello => 36,
+            Self::Trumpet => 52,
+            Self::Soprano => 60,
+            Self::Alto => 53,
+        };
+        MidiNote::from_byte(byte)
+    }
+
+    /// Returns the highest sounding note of this instrument.
+    /// ```
+    /// use staff::{midi, Instrument};
+    ///
+    /// assert_eq!(Instrument::Soprano.high(), midi!(A, 5));
+    /// ```
+    pub const fn high(self) -> MidiNote {
+        let byte = match self {
+            Self::Piano => 108,
+            Self::Guitar => 83,
+            Self::Violin => 105,
+            Self::Viola => 88,
+            Self::Cello | Self::Soprano => 81,
+            Self::Trumpet => 82,
+            Self::Trombone => 77,
+            Self::Alto => 74,
+            Self::Tenor => 69,
+            Self::Bass => 64,
+        };
+        MidiNote::from_byte(byte)
+    }
+
+    pub const fn range(self) -> RangeInclusive<MidiNote> {
+        self.low()..=self.high()
+    }
+}
+
+/// A note outside of an instrument's range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeError {
+    pub note: MidiNote,
+    pub instrument: Instrument,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Note `{}` is outside of the {:?} range `{}` to `{}`",
+            self.note,
+            self.instrument,
+            self.instrument.low(),
+            self.instrument.high()
+        )
+    }
+}
@@ -1,6 +1,43 @@
+use crate::{Note, Pitch};
 use core::fmt::{self, Write};
 use core::ops::{Add, Sub};
 
+/// Direction of an interval from its lower or upper note.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntervalDirection {
+    Ascending,
+    Descending,
+}
+
+/// Quality of a spelled interval, from its size compared to the major or perfect interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntervalQuality {
+    DoublyDiminished,
+    Diminished,
+    Minor,
+    Perfect,
+    Major,
+    Augmented,
+    DoublyAugmented,
+}
+
+impl fmt::Display for IntervalQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::DoublyDiminished => "dd",
+            Self::Diminished => "d",
+            Self::Minor => "m",
+            Self::Perfect => "P",
+            Self::Major => "M",
+            Self::Augmented => "A",
+            Self::DoublyAugmented => "AA",
+        };
+        f.write_str(s)
+    }
+}
+
 /// Music interval in semitones.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -48,6 +85,155 @@ impl Interval {
     pub const fn semitones(self) -> u8 {
         self.semitones
     }
+
+    /// Returns the 5-limit just intonation ratio of this interval within an octave,
+    /// with a minor seventh of 9/5 and a tritone of 45/32.
+    /// ```
+    /// use staff::Interval;
+    ///
+    /// assert_eq!(Interval::PERFECT_FIFTH.just_ratio(), (3, 2));
+    /// assert_eq!(Interval::MAJOR_NINTH.just_ratio(), (9, 8));
+    /// ```
+    pub const fn just_ratio(self) -> (u32, u32) {
+        match self.semitones % 12 {
+            0 => (1, 1),
+            1 => (16, 15),
+            2 => (9, 8),
+            3 => (6, 5),
+            4 => (5, 4),
+            5 => (4, 3),
+            6 => (45, 32),
+            7 => (3, 2),
+            8 => (8, 5),
+            9 => (5, 3),
+            10 => (9, 5),
+            _ => (15, 8),
+        }
+    }
+
+    /// Returns the ascending interval from `from` up to `to`, within an octave.
+    /// ```
+    /// use staff::{Interval, Natural, Note};
+    ///
+    /// let interval = Interval::between_notes(Note::from(Natural::A), Note::C);
+    /// assert_eq!(interval, Interval::MINOR_THIRD);
+    /// ```
+    pub fn between_notes(from: Note, to: Note) -> Self {
+        Pitch::from(to) - Pitch::from(from)
+    }
+
+    /// Returns the number (1 through 7) and quality of the ascending interval from `from` up to `to`,
+    /// using the letters of each note for the number, or `None` if the quality is beyond doubly
+    /// augmented or diminished.
+    /// ```
+    /// use staff::{Interval, IntervalQuality, Natural, Note};
+    ///
+    /// let quality = Interval::quality_between_notes(Note::C, Note::sharp(Natural::G));
+    /// assert_eq!(quality, Some((5, IntervalQuality::Augmented)));
+    ///
+    /// let quality = Interval::quality_between_notes(Note::C, Note::flat(Natural::A));
+    /// assert_eq!(quality, Some((6, IntervalQuality::Minor)));
+    ///
+    /// let quality = Interval::quality_between_notes(Note::from(Natural::B), Note::F);
+    /// assert_eq!(quality, Some((5, IntervalQuality::Diminished)));
+    /// ```
+    pub fn quality_between_notes(from: Note, to: Note) -> Option<(u8, IntervalQuality)> {
+        let steps = (to.natural as u8 + 7 - from.natural as u8) % 7;
+        let semitones = Self::between_notes(from, to).semitones() as i8;
+
+        let major = [0, 2, 4, 5, 7, 9, 11][steps as usize];
+        let mut diff = semitones - major;
+        if diff > 6 {
+            diff -= 12;
+        } else if diff < -6 {
+            diff += 12;
+        }
+
+        let quality = if matches!(steps, 0 | 3 | 4) {
+            match diff {
+                -2 => IntervalQuality::DoublyDiminished,
+                -1 => IntervalQuality::Diminished,
+                0 => IntervalQuality::Perfect,
+                1 => IntervalQuality::Augmented,
+                2 => IntervalQuality::DoublyAugmented,
+                _ => return None,
+            }
+        } else {
+            match diff {
+                -3 => IntervalQuality::DoublyDiminished,
+                -2 => IntervalQuality::Diminished,
+                -1 => IntervalQuality::Minor,
+                0 => IntervalQuality::Major,
+                1 => IntervalQuality::Augmented,
+                2 => IntervalQuality::DoublyAugmented,
+                _ => return None,
+            }
+        };
+        Some((steps + 1, quality))
+    }
+}
+
+/// Interval between two notes in semitones, negative if the second note is lower.
+/// ```
+/// use staff::{midi, Interval};
+///
+/// let interval = midi!(G, 4).interval_to(midi!(C, 3));
+/// assert_eq!(interval.semitones, -19);
+/// assert_eq!(interval.to_interval(), Interval::new(19));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedInterval {
+    pub semitones: i32,
+}
+
+impl SignedInterval {
+    /// Create a new interval, descending if `semitones` is negative.
+    pub const fn new(semitones: i32) -> Self {
+        Self { semitones }
+    }
+
+    /// Create a new ascending interval.
+    pub const fn ascending(semitones: u8) -> Self {
+        Self::new(semitones as i32)
+    }
+
+    /// Create a new descending interval.
+    /// ```
+    /// use staff::{Pitch, SignedInterval};
+    ///
+    /// let interval = SignedInterval::descending(4);
+    /// assert_eq!(interval.semitones, -4);
+    /// assert_eq!(Pitch::E.apply_interval(interval), Pitch::C);
+    /// ```
+    pub const fn descending(semitones: u8) -> Self {
+        Self::new(-(semitones as i32))
+    }
+
+    /// Returns the direction of this interval, where a unison is ascending.
+    pub const fn direction(self) -> IntervalDirection {
+        if self.semitones < 0 {
+            IntervalDirection::Descending
+        } else {
+            IntervalDirection::Ascending
+        }
+    }
+
+    /// Returns the simple or compound interval of this size, ignoring its direction.
+    pub const fn to_interval(self) -> Interval {
+        Interval::new(self.semitones.unsigned_abs() as u8)
+    }
+
+    /// Returns this interval in the opposite direction.
+    /// ```
+    /// use staff::SignedInterval;
+    ///
+    /// let interval = SignedInterval::ascending(4).invert_direction();
+    /// assert_eq!(interval, SignedInterval::descending(4));
+    /// ```
+    pub const fn invert_direction(self) -> Self {
+        Self::new(-self.semitones)
+    }
 }
 
 impl From<u8> for Interval {
@@ -1,10 +1,16 @@
 //! Key signatures
 
-use crate::{note::Accidental, Interval, Natural, Pitch};
-use core::fmt::{self, Write};
+use crate::{
+    note::{self, Accidental, AccidentalPreference},
+    Interval, Natural, Note, Pitch,
+};
+use core::{
+    fmt::{self, Write},
+    str::FromStr,
+};
 
 /// A key signature represented as the total number of sharps or flats.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Key {
     /// The number of sharps in this key
@@ -36,6 +42,17 @@ impl Key {
         Self { sharps: alteration }
     }
 
+    /// Returns the tonic of the major key with this signature.
+    /// ```
+    /// use staff::{Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::A);
+    /// assert_eq!(key.tonic(), Pitch::A);
+    /// ```
+    pub fn tonic(self) -> Pitch {
+        Pitch::C + Interval::new(self.sharps * Interval::PERFECT_FIFTH.semitones())
+    }
+
     /// Returns the number of flats in this key.
     pub fn flats(self) -> u8 {
         Pitch::B.into_byte() + 1 - self.sharps
@@ -45,6 +62,55 @@ impl Key {
         self.sharps <= 6
     }
 
+    /// Returns the notes with an accidental in this key, in the order they are written.
+    /// ```
+    /// use staff::{Key, Natural, Note, Pitch};
+    ///
+    /// let key = Key::major(Pitch::A);
+    /// assert_eq!(
+    ///     key.accidental_notes(),
+    ///     [Note::sharp(Natural::F), Note::sharp(Natural::C), Note::sharp(Natural::G)]
+    /// );
+    ///
+    /// let key = Key::major(Pitch::F);
+    /// assert_eq!(key.accidental_notes(), [Note::flat(Natural::B)]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn accidental_notes(self) -> Vec<Note> {
+        self.into_iter()
+            .map(|natural| Note::new(natural, self.accidental(natural)))
+            .collect()
+    }
+
+    /// Returns the tonic of the major key with this signature,
+    /// spelled with the accidental of the key.
+    fn tonic_note(self) -> Note {
+        let preference = if self.is_sharp() {
+            AccidentalPreference::Sharps
+        } else {
+            AccidentalPreference::Flats
+        };
+        Note::from_pitch_with(self.tonic(), preference)
+    }
+
+    /// Writes the name of the major key with this signature and its number of accidentals.
+    fn write_name(
+        f: &mut fmt::Formatter<'_>,
+        tonic: Note,
+        count: u8,
+        accidental: char,
+    ) -> fmt::Result {
+        write!(f, "{}", tonic.natural)?;
+        if !tonic.accidental.is_natural() {
+            write!(f, "{}", tonic.accidental)?;
+        }
+        f.write_str(" major")?;
+        if count > 0 {
+            write!(f, " ({}{})", count, accidental)?;
+        }
+        Ok(())
+    }
+
     pub fn accidental(self, natural: Natural) -> Accidental {
         // TODO maybe use a `Set`
         self.into_iter()
@@ -81,7 +147,50 @@ impl IntoIterator for Key {
 }
 
 impl fmt::Display for Key {
+    /// Writes the accidentals of this key, like `##`.
+    ///
+    /// The plain form is kept as the bare accidentals for compatibility,
+    /// so the name of the major key and its number of accidentals,
+    /// along with its enharmonic equivalent, are written with the alternate form `{:#}`.
+    /// ```
+    /// use staff::{Key, Pitch};
+    ///
+    /// assert_eq!(Key::major(Pitch::D).to_string(), "##");
+    /// assert_eq!(format!("{:#}", Key::major(Pitch::D)), "D major (2♯)");
+    ///
+    /// assert_eq!(format!("{:#}", Key::major(Pitch::G)), "G major (1♯)");
+    /// assert_eq!(format!("{:#}", Key::major(Pitch::C)), "C major");
+    /// assert_eq!(
+    ///     format!("{:#}", Key::major(Pitch::CSharp)),
+    ///     "D♭ major (5♭) = C♯ major (7♯)"
+    /// );
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let tonic = self.tonic_note();
+            if self.is_sharp() {
+                Self::write_name(f, tonic, self.sharps, '♯')?;
+            } else {
+                Self::write_name(f, tonic, self.flats(), '♭')?;
+            }
+
+            // Keys with five to seven accidentals can be spelled either way
+            return match self.sharps {
+                5 | 6 => {
+                    f.write_str(" = ")?;
+                    let natural = tonic.natural + 1;
+                    let tonic = tonic.respell_as(natural).unwrap();
+                    Self::write_name(f, tonic, Pitch::B.into_byte() + 1 - self.sharps, '♭')
+                }
+                7 => {
+                    f.write_str(" = ")?;
+                    let tonic = tonic.respell_as(tonic.natural + 6).unwrap();
+                    Self::write_name(f, tonic, self.sharps, '♯')
+                }
+                _ => Ok(()),
+            };
+        }
+
         if self.sharps <= 6 {
             for _ in 0..self.sharps {
                 f.write_char('#')?;
@@ -96,6 +205,89 @@ impl fmt::Display for Key {
     }
 }
 
+/// Error parsing a [`Key`].
+#[derive(Debug)]
+pub enum ParseKeyError {
+    Empty,
+    InvalidNote(note::Error),
+    /// A number of sharps or flats greater than 7
+    InvalidAccidentalCount,
+}
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Empty key input"),
+            Self::InvalidNote(error) => write!(f, "{}", error),
+            Self::InvalidAccidentalCount => f.write_str("Expected 0 to 7 sharps or flats"),
+        }
+    }
+}
+
+impl From<note::Error> for ParseKeyError {
+    fn from(error: note::Error) -> Self {
+        Self::InvalidNote(error)
+    }
+}
+
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    /// Parses a key from its tonic, like `F# major`, `Bb` or `Am`,
+    /// or its number of accidentals, like `3 sharps`.
+    ///
+    /// Minor keys return the signature of their relative major.
+    /// ```
+    /// use staff::{Key, Pitch};
+    ///
+    /// assert_eq!("C major".parse::<Key>().unwrap(), Key::major(Pitch::C));
+    /// assert_eq!("Bb".parse::<Key>().unwrap(), Key::major(Pitch::ASharp));
+    /// assert_eq!("Am".parse::<Key>().unwrap(), Key::major(Pitch::C));
+    /// assert_eq!("E minor".parse::<Key>().unwrap(), Key::major(Pitch::G));
+    /// assert_eq!("3 sharps".parse::<Key>().unwrap(), Key::major(Pitch::A));
+    /// assert_eq!("1 flat".parse::<Key>().unwrap(), Key::major(Pitch::F));
+    /// assert!("9 sharps".parse::<Key>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseKeyError::Empty);
+        }
+
+        if let Some((count, accidentals)) = s.split_once(' ') {
+            if let Ok(count) = count.parse::<u8>() {
+                if count > 7 {
+                    return Err(ParseKeyError::InvalidAccidentalCount);
+                }
+                return match accidentals {
+                    "sharp" | "sharps" => Ok(Self { sharps: count }),
+                    "flat" | "flats" => Ok(Self {
+                        sharps: (Pitch::B.into_byte() + 1 - count) % (Pitch::B.into_byte() + 1),
+                    }),
+                    _ => Err(ParseKeyError::InvalidAccidentalCount),
+                };
+            }
+        }
+
+        let (tonic, is_minor) = if let Some(tonic) = s.strip_suffix(" major") {
+            (tonic, false)
+        } else if let Some(tonic) = s.strip_suffix(" minor") {
+            (tonic, true)
+        } else if let Some(tonic) = s.strip_suffix('m') {
+            (tonic, true)
+        } else {
+            (s, false)
+        };
+
+        let tonic = Pitch::from(tonic.trim().parse::<Note>()?);
+        if is_minor {
+            Ok(Self::major(tonic + Interval::MINOR_THIRD))
+        } else {
+            Ok(Self::major(tonic))
+        }
+    }
+}
+
 pub struct Iter {
     natural: Natural,
     remaining: u8,
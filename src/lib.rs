@@ -59,6 +59,9 @@
 pub mod chord;
 pub use chord::Chord;
 
+pub mod clef;
+pub use clef::Clef;
+
 pub mod time;
 
 pub mod fmt;
@@ -68,17 +71,22 @@ pub use fmt::Format;
 #[cfg(feature = "fretboard")]
 pub mod fretboard;
 
+pub mod instrument;
+pub use instrument::Instrument;
+
 mod interval;
-pub use interval::Interval;
+pub use interval::{Interval, IntervalDirection, IntervalQuality, SignedInterval};
 
 mod key;
-pub use crate::key::Key;
+pub use crate::key::{Key, ParseKeyError};
 
 mod natural;
 pub use natural::Natural;
 
 pub mod midi;
 
+pub mod modulation;
+
 pub mod note;
 pub use note::Note;
 
@@ -89,9 +97,15 @@ pub use pitch::Pitch;
 #[cfg(feature = "render")]
 pub mod render;
 
+#[cfg(feature = "std")]
+mod rng;
+
 pub mod scale;
 pub use scale::Scale;
 
+#[cfg(feature = "std")]
+pub mod score;
+
 pub mod set;
 pub use set::Set;
 
@@ -99,6 +113,8 @@ pub use set::Set;
 #[cfg(feature = "synth")]
 pub mod synth;
 
+pub mod transpose;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
 #[cfg(feature = "ui")]
 pub mod ui;
@@ -121,3 +137,82 @@ macro_rules! midi {
         )
     };
 }
+
+/// Creates a [`Note`] from its natural and an optional [`Accidental`](note::Accidental).
+///
+/// Invalid naturals and accidentals fail to compile.
+/// ```
+/// use staff::{note, Natural, Note};
+///
+/// assert_eq!(note!(C), Note::C);
+/// assert_eq!(note!(C, Sharp), Note::sharp(Natural::C));
+/// assert_eq!(note!(B, Flat), Note::flat(Natural::B));
+/// assert_eq!(note!(F, DoubleSharp), Note::double_sharp(Natural::F));
+/// ```
+///
+/// ```compile_fail
+/// use staff::note;
+///
+/// let note = note!(H, Sharp);
+/// ```
+#[macro_export]
+macro_rules! note {
+    ($natural:ident) => {
+        staff::Note::from(staff::Natural::$natural)
+    };
+    ($natural:ident, $accidental:ident) => {
+        staff::Note::new(
+            staff::Natural::$natural,
+            staff::note::Accidental::$accidental,
+        )
+    };
+}
+
+/// Creates a [`Chord`] from its root and quality, in the fourth octave unless an octave is given.
+///
+/// Qualities are `maj`, `m`, `7`, `maj7`, `m7`, `m7b5` and `dim7`,
+/// and anything else fails to compile.
+/// ```
+/// use staff::{chord, midi, Chord};
+///
+/// assert_eq!(chord!(C, maj), Chord::major(midi!(C, 4)));
+/// assert_eq!(chord!(C, maj7), Chord::major(midi!(C, 4)).major_seventh());
+/// assert_eq!(chord!(G, 3, 7), Chord::seventh(midi!(G, 3)));
+/// assert_eq!(chord!(B, m7b5), Chord::half_diminished(midi!(B, 4)));
+/// ```
+///
+/// ```compile_fail
+/// use staff::chord;
+///
+/// let chord = chord!(C, sus7);
+/// ```
+#[macro_export]
+macro_rules! chord {
+    ($pitch:ident, $quality:tt) => {
+        $crate::chord!($pitch, 4, $quality)
+    };
+    ($pitch:ident, $octave:literal, $quality:tt) => {
+        $crate::chord!(@$quality staff::midi!($pitch, $octave))
+    };
+    (@maj $root:expr) => {
+        staff::Chord::major($root)
+    };
+    (@m $root:expr) => {
+        staff::Chord::minor($root)
+    };
+    (@7 $root:expr) => {
+        staff::Chord::seventh($root)
+    };
+    (@maj7 $root:expr) => {
+        staff::Chord::major($root).major_seventh()
+    };
+    (@m7 $root:expr) => {
+        staff::Chord::minor_seventh($root)
+    };
+    (@m7b5 $root:expr) => {
+        staff::Chord::half_diminished($root)
+    };
+    (@dim7 $root:expr) => {
+        staff::Chord::diminished_seventh($root)
+    };
+}
@@ -0,0 +1,220 @@
+use super::message::{ControlMessage, Message, NoteMessage, SysExMessage};
+use crate::{
+    time::{DurationKind, Tempo, TimeSignature},
+    Key,
+};
+
+/// A message at a position in a MIDI file, measured in ticks from the start.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimedMessage {
+    pub tick: u32,
+    pub message: Message,
+}
+
+/// A named track of messages sorted by tick.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MidiTrack {
+    pub name: String,
+    pub messages: Vec<TimedMessage>,
+}
+
+impl MidiTrack {
+    pub fn new(name: impl Into<String>, messages: Vec<TimedMessage>) -> Self {
+        Self {
+            name: name.into(),
+            messages,
+        }
+    }
+}
+
+/// A MIDI file with a single track of messages, and optionally more tracks played alongside it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MidiFile {
+    /// The number of ticks in each quarter note beat.
+    pub ticks_per_beat: u16,
+    pub tempo: Tempo,
+    pub time_signature: TimeSignature,
+    /// The key signature written to the first track, if any.
+    pub key: Option<Key>,
+    /// Messages sorted by tick.
+    pub messages: Vec<TimedMessage>,
+    /// Additional tracks, which make this a format 1 file.
+    pub tracks: Vec<MidiTrack>,
+}
+
+impl MidiFile {
+    pub const DEFAULT_TICKS_PER_BEAT: u16 = 480;
+
+    pub fn new(tempo: Tempo, time_signature: TimeSignature) -> Self {
+        Self {
+            ticks_per_beat: Self::DEFAULT_TICKS_PER_BEAT,
+            tempo,
+            time_signature,
+            key: None,
+            messages: Vec::new(),
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Encodes this file as a Standard MIDI File.
+    ///
+    /// A file without additional tracks is encoded as format 0, with every note message on channel 1.
+    /// Otherwise it is encoded as format 1, and the notes of each additional track
+    /// are on the next channel after the previous track's, skipping the drum channel 10.
+    ///
+    /// That leaves 14 channels for additional tracks, so any tracks after the 14th
+    /// start again from channel 2 and share channels with earlier tracks.
+    /// ```
+    /// use staff::midi::MidiFile;
+    /// use staff::time::{DurationKind, Tempo, TimeSignature};
+    ///
+    /// let file = MidiFile::new(Tempo::new(120.), TimeSignature::new(DurationKind::Quarter, 4));
+    /// let bytes = file.to_bytes();
+    ///
+    /// assert_eq!(&bytes[..4], b"MThd");
+    /// assert_eq!(&bytes[14..18], b"MTrk");
+    /// ```
+    ///
+    /// ```
+    /// use staff::midi;
+    /// use staff::midi::{MidiFile, MidiTrack, TimedMessage};
+    /// use staff::midi::message::{Message, NoteMessage};
+    /// use staff::time::{DurationKind, Tempo, TimeSignature};
+    ///
+    /// let note_on = Message::NoteOn(NoteMessage { note: midi!(C, 4), velocity: 100 });
+    /// let track = MidiTrack::new("", vec![TimedMessage { tick: 0, message: note_on }]);
+    ///
+    /// let mut file = MidiFile::new(Tempo::new(120.), TimeSignature::new(DurationKind::Quarter, 4));
+    /// file.tracks = vec![track; 9];
+    /// let bytes = file.to_bytes();
+    ///
+    /// // The ninth track is on channel 11
+    /// assert!(bytes.windows(2).any(|status| status == [0x9A, 60]));
+    /// assert!(!bytes.windows(2).any(|status| status == [0x99, 60]));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut first = Vec::new();
+
+        let micros = self.tempo.micros_per_beat().to_be_bytes();
+        first.extend([0, 0xFF, 0x51, 0x03, micros[1], micros[2], micros[3]]);
+
+        let denominator = match self.time_signature.unit {
+            DurationKind::Whole => 0,
+            DurationKind::Half => 1,
+            DurationKind::Quarter => 2,
+            DurationKind::Eigth => 3,
+        };
+        first.extend([
+            0,
+            0xFF,
+            0x58,
+            0x04,
+            self.time_signature.beats,
+            denominator,
+            24,
+            8,
+        ]);
+
+        // Flats are written as a negative number of sharps, always in a major key
+        if let Some(key) = self.key {
+            let accidentals = if key.is_sharp() {
+                key.sharps as i8
+            } else {
+                -(key.flats() as i8)
+            };
+            first.extend([0, 0xFF, 0x59, 0x02, accidentals as u8, 0]);
+        }
+        write_messages(&mut first, &self.messages, 0);
+
+        let mut tracks = vec![first];
+        for (idx, track) in self.tracks.iter().enumerate() {
+            let mut bytes = Vec::new();
+            if !track.name.is_empty() {
+                bytes.extend([0, 0xFF, 0x03]);
+                write_variable_length(&mut bytes, track.name.len() as u32);
+                bytes.extend(track.name.as_bytes());
+            }
+            write_messages(&mut bytes, &track.messages, track_channel(idx));
+            tracks.push(bytes);
+        }
+
+        let format: u16 = if self.tracks.is_empty() { 0 } else { 1 };
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"MThd");
+        bytes.extend(6u32.to_be_bytes());
+        bytes.extend(format.to_be_bytes());
+        bytes.extend((tracks.len() as u16).to_be_bytes());
+        bytes.extend(self.ticks_per_beat.to_be_bytes());
+        for mut track in tracks {
+            track.extend([0, 0xFF, 0x2F, 0x00]);
+            bytes.extend_from_slice(b"MTrk");
+            bytes.extend((track.len() as u32).to_be_bytes());
+            bytes.extend(track);
+        }
+        bytes
+    }
+}
+
+/// Returns the zero-indexed channel of the additional track at `idx`,
+/// from channel 2 to 16 without the General MIDI drum channel 10.
+fn track_channel(idx: usize) -> u8 {
+    const DRUMS: u8 = 9;
+    let channel = (idx % 14) as u8 + 1;
+    if channel >= DRUMS {
+        channel + 1
+    } else {
+        channel
+    }
+}
+
+/// Writes each of `messages` after the time since the previous one,
+/// with note messages on `channel` and SysEx messages framed by `F0` and `F7`.
+fn write_messages(bytes: &mut Vec<u8>, messages: &[TimedMessage], channel: u8) {
+    let mut tick = 0;
+    for timed in messages {
+        write_variable_length(bytes, timed.tick.saturating_sub(tick));
+        tick = tick.max(timed.tick);
+
+        match &timed.message {
+            Message::NoteOff(NoteMessage { note, velocity }) => {
+                bytes.extend([0x80 | channel, note.into_byte(), *velocity]);
+            }
+            Message::NoteOn(NoteMessage { note, velocity }) => {
+                bytes.extend([0x90 | channel, note.into_byte(), *velocity]);
+            }
+            Message::ControlChange(ControlMessage {
+                channel,
+                controller,
+                value,
+            }) => bytes.extend([0xB0 | (channel & 0x0F), *controller, *value]),
+            Message::SysEx(SysExMessage {
+                manufacturer_id,
+                data,
+            }) => {
+                bytes.push(0xF0);
+                write_variable_length(bytes, data.len() as u32 + 2);
+                bytes.push(*manufacturer_id);
+                bytes.extend(data);
+                bytes.push(0xF7);
+            }
+        }
+    }
+}
+
+fn write_variable_length(bytes: &mut Vec<u8>, mut value: u32) {
+    let mut buf = [0; 4];
+    let mut len = 0;
+    loop {
+        buf[len] = (value & 0x7F) as u8;
+        len += 1;
+        value >>= 7;
+        if value == 0 {
+            break;
+        }
+    }
+
+    for idx in (0..len).rev() {
+        let continuation = if idx > 0 { 0x80 } else { 0 };
+        bytes.push(buf[idx] | continuation);
+    }
+}
@@ -1,13 +1,167 @@
 use super::MidiNote;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct NoteMessage {
     pub note: MidiNote,
     pub velocity: u8,
 }
 
+/// A control change message that sets the value of a controller on a channel.
+/// ```
+/// use staff::midi::message::{ControlMessage, Message, MidiController};
+///
+/// let message: Message = [0xB0, 64, 127].into_iter().collect();
+///
+/// let Message::ControlChange(cc) = message else { panic!() };
+/// assert_eq!(cc.controller(), Some(MidiController::Sustain));
+/// assert_eq!(cc.value, 127);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ControlMessage {
+    pub channel: u8,
+    pub controller: u8,
+    pub value: u8,
+}
+
+impl ControlMessage {
+    pub fn new(channel: u8, controller: MidiController, value: u8) -> Self {
+        Self {
+            channel,
+            controller: controller as u8,
+            value,
+        }
+    }
+
+    /// Returns the standard controller for this message's controller number, if any.
+    pub fn controller(&self) -> Option<MidiController> {
+        MidiController::try_from(self.controller).ok()
+    }
+}
+
+/// Standard MIDI controller numbers.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MidiController {
+    BankSelect = 0,
+    ModulationWheel = 1,
+    BreathController = 2,
+    FootController = 4,
+    PortamentoTime = 5,
+    Volume = 7,
+    Balance = 8,
+    Pan = 10,
+    Expression = 11,
+    Sustain = 64,
+    Portamento = 65,
+    Sostenuto = 66,
+    SoftPedal = 67,
+    AllSoundOff = 120,
+    ResetAllControllers = 121,
+    AllNotesOff = 123,
+}
+
+impl TryFrom<u8> for MidiController {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let controller = match value {
+            0 => Self::BankSelect,
+            1 => Self::ModulationWheel,
+            2 => Self::BreathController,
+            4 => Self::FootController,
+            5 => Self::PortamentoTime,
+            7 => Self::Volume,
+            8 => Self::Balance,
+            10 => Self::Pan,
+            11 => Self::Expression,
+            64 => Self::Sustain,
+            65 => Self::Portamento,
+            66 => Self::Sostenuto,
+            67 => Self::SoftPedal,
+            120 => Self::AllSoundOff,
+            121 => Self::ResetAllControllers,
+            123 => Self::AllNotesOff,
+            _ => return Err(value),
+        };
+        Ok(controller)
+    }
+}
+
+/// A System Exclusive message for a manufacturer's devices.
+/// ```
+/// use staff::midi::message::{Message, SysExMessage};
+///
+/// let message: Message = [0xF0, 0x41, 0x10, 0x42, 0xF7].into_iter().collect();
+/// assert_eq!(message, Message::SysEx(SysExMessage::new(0x41, vec![0x10, 0x42])));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SysExMessage {
+    pub manufacturer_id: u8,
+    /// The bytes after the manufacturer ID, without the end of exclusive byte.
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl SysExMessage {
+    /// The manufacturer ID of universal non-real-time messages.
+    pub const NON_REAL_TIME: u8 = 0x7E;
+
+    /// The manufacturer ID of universal real-time messages.
+    pub const REAL_TIME: u8 = 0x7F;
+
+    /// The device ID that addresses every device.
+    pub const ALL_DEVICES: u8 = 0x7F;
+
+    pub fn new(manufacturer_id: u8, data: Vec<u8>) -> Self {
+        Self {
+            manufacturer_id,
+            data,
+        }
+    }
+}
+
+/// A MIDI message, which is `Copy` without the `std` feature since only SysEx messages own data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "std"), derive(Copy))]
 pub enum Message {
     NoteOff(NoteMessage),
     NoteOn(NoteMessage),
+    ControlChange(ControlMessage),
+    #[cfg(feature = "std")]
+    SysEx(SysExMessage),
+}
+
+#[cfg(feature = "std")]
+impl Message {
+    /// Creates a universal non-real-time SysEx message for every device,
+    /// with `data` after the `sub_id`.
+    /// ```
+    /// use staff::midi::message::{Message, SysExMessage};
+    ///
+    /// // General MIDI system on
+    /// let message = Message::universal_sysex(0x09, &[0x01]);
+    ///
+    /// let Message::SysEx(sysex) = message else { panic!() };
+    /// assert_eq!(sysex.manufacturer_id, SysExMessage::NON_REAL_TIME);
+    /// assert_eq!(sysex.data, [0x7F, 0x09, 0x01]);
+    /// ```
+    pub fn universal_sysex(sub_id: u8, data: &[u8]) -> Self {
+        Self::universal(SysExMessage::NON_REAL_TIME, sub_id, data)
+    }
+
+    /// Creates a universal real-time SysEx message for every device,
+    /// with `data` after the `sub_id`.
+    pub fn universal_real_time_sysex(sub_id: u8, data: &[u8]) -> Self {
+        Self::universal(SysExMessage::REAL_TIME, sub_id, data)
+    }
+
+    fn universal(manufacturer_id: u8, sub_id: u8, data: &[u8]) -> Self {
+        let mut bytes = Vec::with_capacity(data.len() + 2);
+        bytes.extend([SysExMessage::ALL_DEVICES, sub_id]);
+        bytes.extend_from_slice(data);
+        Self::SysEx(SysExMessage::new(manufacturer_id, bytes))
+    }
 }
 
 impl FromIterator<u8> for Message {
@@ -25,6 +179,21 @@ impl FromIterator<u8> for Message {
                 let velocity = iter.next().unwrap();
                 Message::NoteOn(NoteMessage { note, velocity })
             }
+            0b1011 => {
+                let controller = iter.next().unwrap();
+                let value = iter.next().unwrap();
+                Message::ControlChange(ControlMessage {
+                    channel: status & 0x0F,
+                    controller,
+                    value,
+                })
+            }
+            #[cfg(feature = "std")]
+            0b1111 if status == 0xF0 => {
+                let manufacturer_id = iter.next().unwrap();
+                let data = iter.take_while(|byte| *byte != 0xF7).collect();
+                Message::SysEx(SysExMessage::new(manufacturer_id, data))
+            }
             _ => todo!(),
         }
     }
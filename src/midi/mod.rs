@@ -1,6 +1,9 @@
 //! Midi notes, octaves, and sets
 
-use crate::{Interval, Pitch};
+use crate::{
+    clef::{Clef, StaffPosition},
+    Instrument, Interval, Pitch, SignedInterval,
+};
 use core::fmt;
 use core::ops::{Add, Sub};
 
@@ -9,6 +12,18 @@ pub use octave::Octave;
 
 pub mod message;
 
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::{MidiFile, MidiTrack, TimedMessage};
+
+#[cfg(feature = "std")]
+mod piano_roll;
+#[cfg(feature = "std")]
+pub(crate) use piano_roll::note_messages;
+#[cfg(feature = "std")]
+pub use piano_roll::{PianoRoll, PianoRollNote};
+
 mod midi_set;
 pub use midi_set::MidiSet;
 
@@ -88,6 +103,41 @@ impl MidiNote {
             interval
         }
     }
+
+    /// Returns the interval from this note to `other`, descending if `other` is lower.
+    /// ```
+    /// use staff::midi;
+    ///
+    /// assert_eq!(midi!(C, 4).interval_to(midi!(E, 5)).semitones, 16);
+    /// assert_eq!(midi!(C, 4).interval_to(midi!(A, 3)).semitones, -3);
+    /// ```
+    pub const fn interval_to(self, other: Self) -> SignedInterval {
+        SignedInterval::new(other.into_byte() as i32 - self.into_byte() as i32)
+    }
+
+    /// Returns true if `instrument` can play this note.
+    /// ```
+    /// use staff::{midi, Instrument};
+    ///
+    /// assert!(midi!(C, 2).is_in_range(Instrument::Cello));
+    /// assert!(!midi!(C, 2).is_in_range(Instrument::Viola));
+    /// ```
+    pub fn is_in_range(self, instrument: Instrument) -> bool {
+        instrument.range().contains(&self)
+    }
+
+    /// Returns the number of lines and spaces this note is above the bottom line of the `clef`'s staff,
+    /// or a negative number for notes below it.
+    /// ```
+    /// use staff::{midi, Clef};
+    ///
+    /// assert_eq!(midi!(E, 4).staff_position(Clef::Treble), 0);
+    /// assert_eq!(midi!(C, 4).staff_position(Clef::Treble), -2);
+    /// assert_eq!(midi!(C, 4).staff_position(Clef::Bass), 10);
+    /// ```
+    pub fn staff_position(self, clef: Clef) -> i8 {
+        StaffPosition::new(self, clef).line_or_space - 2
+    }
 }
 
 impl Add<Interval> for MidiNote {
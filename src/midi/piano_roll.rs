@@ -0,0 +1,204 @@
+use super::{
+    file::{MidiFile, TimedMessage},
+    message::{Message, NoteMessage},
+    MidiNote,
+};
+use crate::time::{Duration, Tempo, TimeSignature};
+
+/// A note in a [`PianoRoll`], timed in quarter note beats.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PianoRollNote {
+    pub pitch: MidiNote,
+    pub start_beat: f64,
+    pub duration_beats: f64,
+    pub velocity: u8,
+}
+
+impl PianoRollNote {
+    pub fn new(pitch: MidiNote, start_beat: f64, duration_beats: f64, velocity: u8) -> Self {
+        Self {
+            pitch,
+            start_beat,
+            duration_beats,
+            velocity,
+        }
+    }
+
+    pub fn end_beat(&self) -> f64 {
+        self.start_beat + self.duration_beats
+    }
+}
+
+/// Editable sequence of notes sorted by start time.
+/// ```
+/// use staff::midi;
+/// use staff::midi::{PianoRoll, PianoRollNote};
+/// use staff::time::DurationKind;
+///
+/// let mut roll = PianoRoll::new();
+/// roll.add_note(PianoRollNote::new(midi!(E, 4), 1.1, 0.9, 100));
+/// roll.add_note(PianoRollNote::new(midi!(C, 4), 0.05, 1., 100));
+///
+/// roll.quantize(DurationKind::Eigth.into());
+/// roll.transpose(2);
+///
+/// let notes = roll.notes();
+/// assert_eq!(notes[0], PianoRollNote::new(midi!(D, 4), 0., 1., 100));
+/// assert_eq!(notes[1], PianoRollNote::new(midi!(FSharp, 4), 1., 1., 100));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PianoRoll {
+    notes: Vec<PianoRollNote>,
+}
+
+impl PianoRoll {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the notes in this piano roll, sorted by start time and then pitch.
+    pub fn notes(&self) -> &[PianoRollNote] {
+        &self.notes
+    }
+
+    pub fn add_note(&mut self, note: PianoRollNote) {
+        let idx = self.notes.partition_point(|other| {
+            (other.start_beat, other.pitch) <= (note.start_beat, note.pitch)
+        });
+        self.notes.insert(idx, note);
+    }
+
+    /// Removes and returns the note with `pitch` starting at `start_beat`, if any.
+    pub fn remove_note(&mut self, pitch: MidiNote, start_beat: f64) -> Option<PianoRollNote> {
+        let idx = self
+            .notes
+            .iter()
+            .position(|note| note.pitch == pitch && note.start_beat == start_beat)?;
+        Some(self.notes.remove(idx))
+    }
+
+    /// Snaps the start and length of each note to the nearest multiple of `grid`.
+    ///
+    /// Notes are kept at least one grid step long.
+    pub fn quantize(&mut self, grid: Duration) {
+        let step = grid.beats(4);
+        for note in &mut self.notes {
+            note.start_beat = (note.start_beat / step).round() * step;
+            note.duration_beats = (note.duration_beats / step).round().max(1.) * step;
+        }
+        self.sort();
+    }
+
+    /// Moves every note by `semitones`, dropping notes that leave the MIDI range.
+    pub fn transpose(&mut self, semitones: i8) {
+        self.notes.retain_mut(|note| {
+            match u8::try_from(note.pitch.into_byte() as i16 + semitones as i16) {
+                Ok(byte) if byte <= 127 => {
+                    note.pitch = MidiNote::from_byte(byte);
+                    true
+                }
+                _ => false,
+            }
+        });
+        self.sort();
+    }
+
+    /// Scales the start and length of every note by `factor`.
+    pub fn time_stretch(&mut self, factor: f64) {
+        for note in &mut self.notes {
+            note.start_beat *= factor;
+            note.duration_beats *= factor;
+        }
+    }
+
+    /// Returns a MIDI file with a note on and off message for each note.
+    /// ```
+    /// use staff::midi;
+    /// use staff::midi::{PianoRoll, PianoRollNote};
+    /// use staff::time::{DurationKind, Tempo, TimeSignature};
+    ///
+    /// let mut roll = PianoRoll::new();
+    /// roll.add_note(PianoRollNote::new(midi!(C, 4), 0., 1., 100));
+    /// roll.add_note(PianoRollNote::new(midi!(G, 4), 0.5, 2., 80));
+    ///
+    /// let time_signature = TimeSignature::new(DurationKind::Quarter, 4);
+    /// let file = roll.clone().to_midi_file(Tempo::new(90.), time_signature);
+    /// assert_eq!(file.messages.len(), 4);
+    ///
+    /// assert_eq!(PianoRoll::from_midi_file(file), roll);
+    /// ```
+    pub fn to_midi_file(self, tempo: Tempo, time_signature: TimeSignature) -> MidiFile {
+        let mut file = MidiFile::new(tempo, time_signature);
+        file.messages = note_messages(&self.notes, file.ticks_per_beat);
+        file
+    }
+
+    /// Returns a piano roll of the notes in `f`, pairing each note on message with the next
+    /// note off (or zero velocity note on) of the same pitch.
+    pub fn from_midi_file(f: MidiFile) -> Self {
+        let beats = |tick: u32| tick as f64 / f.ticks_per_beat as f64;
+
+        let mut roll = Self::new();
+        let mut held: Vec<(MidiNote, u32, u8)> = Vec::new();
+        for timed in &f.messages {
+            match timed.message {
+                Message::NoteOn(NoteMessage { note, velocity }) if velocity > 0 => {
+                    held.push((note, timed.tick, velocity));
+                }
+                Message::NoteOn(NoteMessage { note, .. })
+                | Message::NoteOff(NoteMessage { note, .. }) => {
+                    if let Some(idx) = held.iter().position(|(midi, ..)| *midi == note) {
+                        let (pitch, start, velocity) = held.remove(idx);
+                        let start_beat = beats(start);
+                        let duration_beats = beats(timed.tick) - start_beat;
+                        roll.add_note(PianoRollNote::new(
+                            pitch,
+                            start_beat,
+                            duration_beats,
+                            velocity,
+                        ));
+                    }
+                }
+                Message::ControlChange(_) | Message::SysEx(_) => {}
+            }
+        }
+        roll
+    }
+
+    fn sort(&mut self) {
+        self.notes.sort_by(|a, b| {
+            a.start_beat
+                .total_cmp(&b.start_beat)
+                .then(a.pitch.cmp(&b.pitch))
+        });
+    }
+}
+
+/// Returns a note on and off message for each of `notes`, sorted by tick.
+pub(crate) fn note_messages(notes: &[PianoRollNote], ticks_per_beat: u16) -> Vec<TimedMessage> {
+    let ticks = |beats: f64| (beats * ticks_per_beat as f64).round() as u32;
+
+    let mut messages: Vec<_> = notes
+        .iter()
+        .flat_map(|note| {
+            let msg = NoteMessage {
+                note: note.pitch,
+                velocity: note.velocity,
+            };
+            [
+                TimedMessage {
+                    tick: ticks(note.start_beat),
+                    message: Message::NoteOn(msg),
+                },
+                TimedMessage {
+                    tick: ticks(note.end_beat()),
+                    message: Message::NoteOff(NoteMessage { velocity: 0, ..msg }),
+                },
+            ]
+        })
+        .collect();
+
+    // Release notes before starting new ones on the same tick
+    messages.sort_by_key(|timed| (timed.tick, matches!(timed.message, Message::NoteOn(_))));
+    messages
+}
@@ -0,0 +1,115 @@
+//! Key changes
+
+use crate::{Chord, Key, Pitch};
+
+#[cfg(feature = "std")]
+use crate::{
+    midi::{MidiNote, Octave},
+    Interval,
+};
+
+/// How a piece moves from one key to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModulationType {
+    /// Moves to the new key without preparation.
+    Direct,
+    /// Passes through a chord that belongs to both keys.
+    PivotChord,
+    /// Repeats a phrase in the new key.
+    Sequential,
+    /// Moves through chromatic voice leading.
+    Chromatic,
+    /// Respells a chord, such as a diminished seventh, to reach a distant key.
+    Enharmonic,
+}
+
+/// A change from one key to another.
+/// ```
+/// use staff::{Key, Pitch};
+/// use staff::modulation::{Modulation, ModulationType};
+///
+/// let modulation = Modulation::new(
+///     Key::major(Pitch::C),
+///     Key::major(Pitch::E),
+///     None,
+///     ModulationType::Direct,
+/// );
+/// assert_eq!(modulation.distance(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modulation {
+    pub from_key: Key,
+    pub to_key: Key,
+    pub pivot_chord: Option<Chord>,
+    pub modulation_type: ModulationType,
+}
+
+impl Modulation {
+    pub fn new(
+        from_key: Key,
+        to_key: Key,
+        pivot_chord: Option<Chord>,
+        modulation_type: ModulationType,
+    ) -> Self {
+        Self {
+            from_key,
+            to_key,
+            pivot_chord,
+            modulation_type,
+        }
+    }
+
+    /// Returns the number of steps between the two keys on the circle of fifths.
+    pub fn distance(&self) -> u8 {
+        let keys = Pitch::B.into_byte() + 1;
+        let steps = (self.to_key.sharps + keys - self.from_key.sharps) % keys;
+        steps.min(keys - steps)
+    }
+
+    /// Returns a path of pivot chord modulations from `from` to `to`,
+    /// moving one step around the circle of fifths at a time.
+    ///
+    /// Each pivot is the minor chord shared by neighboring keys,
+    /// such as A minor (vi in C and ii in G).
+    /// ```
+    /// use staff::{Key, Pitch};
+    /// use staff::modulation::Modulation;
+    ///
+    /// let path = Modulation::path(Key::major(Pitch::C), Key::major(Pitch::D));
+    ///
+    /// assert_eq!(path.len(), 2);
+    /// assert_eq!(path[0].to_key, Key::major(Pitch::G));
+    /// assert_eq!(path[0].pivot_chord.as_ref().unwrap().to_string(), "Am");
+    /// assert_eq!(path[1].to_key, Key::major(Pitch::D));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn path(from: Key, to: Key) -> Vec<Self> {
+        let keys = Pitch::B.into_byte() + 1;
+        let direct = Self::new(from, to, None, ModulationType::Direct);
+        let is_sharpward = (to.sharps + keys - from.sharps) % keys <= keys / 2;
+
+        let mut key = from;
+        (0..direct.distance())
+            .map(|_| {
+                let (next, pivot) = if is_sharpward {
+                    let next = Key {
+                        sharps: (key.sharps + 1) % keys,
+                    };
+                    (next, key.tonic() + Interval::MAJOR_SIXTH)
+                } else {
+                    let next = Key {
+                        sharps: (key.sharps + keys - 1) % keys,
+                    };
+                    (next, key.tonic() + Interval::MAJOR_SECOND)
+                };
+
+                let pivot = Chord::minor(MidiNote::new(pivot, Octave::FOUR));
+                let modulation = Self::new(key, next, Some(pivot), ModulationType::PivotChord);
+                key = next;
+                modulation
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
 //! Natural pitches
 
+use crate::Pitch;
 use core::{fmt, ops::Add, str::FromStr};
 
 /// A natural pitch
@@ -48,6 +49,24 @@ impl TryFrom<char> for Natural {
     }
 }
 
+impl TryFrom<Pitch> for Natural {
+    type Error = Pitch;
+
+    fn try_from(pitch: Pitch) -> Result<Self, Self::Error> {
+        let natural = match pitch {
+            Pitch::A => Self::A,
+            Pitch::B => Self::B,
+            Pitch::C => Self::C,
+            Pitch::D => Self::D,
+            Pitch::E => Self::E,
+            Pitch::F => Self::F,
+            Pitch::G => Self::G,
+            _ => return Err(pitch),
+        };
+        Ok(natural)
+    }
+}
+
 impl From<u8> for Natural {
     fn from(byte: u8) -> Self {
         // Safety: `byte` is guranteed to be in range of `Natural`
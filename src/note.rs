@@ -1,6 +1,6 @@
 //! Formatted notes
 
-use crate::Natural;
+use crate::{Interval, Key, Natural, Pitch};
 use core::{
     fmt::{self, Write},
     str::FromStr,
@@ -47,6 +47,16 @@ impl<'a> dioxus::prelude::IntoAttributeValue<'a> for Accidental {
     }
 }
 
+/// Preferred accidental when spelling a pitch as a note.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccidentalPreference {
+    Sharps,
+    Flats,
+    /// Spell notes as they appear in the key, otherwise with the key's accidental.
+    KeyBased(Key),
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Note {
@@ -85,6 +95,154 @@ impl Note {
     pub const fn double_sharp(natural: Natural) -> Self {
         Self::new(natural, Accidental::DoubleSharp)
     }
+
+    /// Spell a pitch as a note with the given accidental preference.
+    /// Natural pitches are spelled without an accidental unless the key uses another spelling.
+    /// ```
+    /// use staff::{Key, Natural, Note, Pitch};
+    /// use staff::note::AccidentalPreference;
+    ///
+    /// let note = Note::from_pitch_with(Pitch::DSharp, AccidentalPreference::Flats);
+    /// assert_eq!(note, Note::flat(Natural::E));
+    ///
+    /// let key = Key::major(Pitch::FSharp);
+    /// let note = Note::from_pitch_with(Pitch::F, AccidentalPreference::KeyBased(key));
+    /// assert_eq!(note, Note::sharp(Natural::E));
+    /// ```
+    pub fn from_pitch_with(pitch: Pitch, preference: AccidentalPreference) -> Self {
+        let is_sharp = match preference {
+            AccidentalPreference::Sharps => true,
+            AccidentalPreference::Flats => false,
+            AccidentalPreference::KeyBased(key) => {
+                let note = key
+                    .into_iter()
+                    .map(|natural| Self::new(natural, key.accidental(natural)))
+                    .find(|note| Pitch::from(*note) == pitch);
+                if let Some(note) = note {
+                    return note;
+                }
+                key.is_sharp()
+            }
+        };
+
+        if let Ok(natural) = Natural::try_from(pitch) {
+            Self::from(natural)
+        } else if is_sharp {
+            let natural = Natural::try_from(pitch - Interval::MINOR_SECOND).unwrap();
+            Self::sharp(natural)
+        } else {
+            let natural = Natural::try_from(pitch + Interval::MINOR_SECOND).unwrap();
+            Self::flat(natural)
+        }
+    }
+
+    /// Spell the pitch of a MIDI note number as a note, preferring sharps.
+    /// ```
+    /// use staff::{Natural, Note};
+    ///
+    /// assert_eq!(Note::from_midi_sharp(61), Note::sharp(Natural::C));
+    /// assert_eq!(Note::from_midi_sharp(64), Note::E);
+    /// ```
+    pub fn from_midi_sharp(number: u8) -> Self {
+        Self::from_pitch_with(Pitch::from(number), AccidentalPreference::Sharps)
+    }
+
+    /// Spell the pitch of a MIDI note number as a note, preferring flats.
+    /// ```
+    /// use staff::{Natural, Note};
+    ///
+    /// assert_eq!(Note::from_midi_flat(61), Note::flat(Natural::D));
+    /// assert_eq!(Note::from_midi_flat(64), Note::E);
+    /// ```
+    pub fn from_midi_flat(number: u8) -> Self {
+        Self::from_pitch_with(Pitch::from(number), AccidentalPreference::Flats)
+    }
+
+    /// Returns the enharmonic equivalent of this note spelled with the given natural,
+    /// or `None` if that would need more than a double sharp or flat.
+    /// ```
+    /// use staff::{Natural, Note};
+    ///
+    /// let note = Note::C.respell_as(Natural::D);
+    /// assert_eq!(note, Some(Note::double_flat(Natural::D)));
+    ///
+    /// assert_eq!(Note::C.respell_as(Natural::E), None);
+    /// ```
+    pub fn respell_as(self, natural: Natural) -> Option<Self> {
+        let accidental = match Pitch::from(self) - Pitch::from(natural) {
+            Interval::UNISON => Accidental::Natural,
+            Interval::MINOR_SECOND => Accidental::Sharp,
+            Interval::MAJOR_SECOND => Accidental::DoubleSharp,
+            Interval::MAJOR_SEVENTH => Accidental::Flat,
+            Interval::MINOR_SEVENTH => Accidental::DoubleFlat,
+            _ => return None,
+        };
+        Some(Self::new(natural, accidental))
+    }
+
+    /// Returns the note `interval` above this note, spelled with the letter for the interval's size.
+    ///
+    /// The tritone is spelled as an augmented fourth.
+    /// ```
+    /// use staff::{Interval, Natural, Note};
+    ///
+    /// let note = Note::E.interval_above(Interval::MINOR_THIRD);
+    /// assert_eq!(note, Note::G);
+    ///
+    /// let note = Note::sharp(Natural::F).interval_above(Interval::MAJOR_THIRD);
+    /// assert_eq!(note, Note::sharp(Natural::A));
+    ///
+    /// let note = Note::flat(Natural::E).interval_above(Interval::MINOR_SEVENTH);
+    /// assert_eq!(note, Note::flat(Natural::D));
+    /// ```
+    pub fn interval_above(self, interval: Interval) -> Self {
+        let steps = letter_steps(interval);
+        let pitch = Pitch::from(self) + interval;
+        self.spell(pitch, self.natural + steps)
+    }
+
+    /// Returns the note `interval` below this note, spelled with the letter for the interval's size.
+    /// ```
+    /// use staff::{Interval, Natural, Note};
+    ///
+    /// let note = Note::C.interval_below(Interval::MAJOR_THIRD);
+    /// assert_eq!(note, Note::flat(Natural::A));
+    /// ```
+    pub fn interval_below(self, interval: Interval) -> Self {
+        let steps = letter_steps(interval);
+        let pitch = Pitch::from(self) - interval;
+        self.spell(pitch, self.natural + (7 - steps) % 7)
+    }
+
+    fn spell(self, pitch: Pitch, natural: Natural) -> Self {
+        let note = Self::from_pitch_with(pitch, AccidentalPreference::Sharps);
+        note.respell_as(natural).unwrap_or(note)
+    }
+
+    /// Returns the enharmonic equivalent of this note spelled with the given preference.
+    /// ```
+    /// use staff::{Natural, Note};
+    /// use staff::note::AccidentalPreference;
+    ///
+    /// let note = Note::double_sharp(Natural::C).spelled_with(AccidentalPreference::Sharps);
+    /// assert_eq!(note, Note::D);
+    /// ```
+    pub fn spelled_with(self, preference: AccidentalPreference) -> Self {
+        Self::from_pitch_with(self.into(), preference)
+    }
+}
+
+/// Returns the number of letters spanned by `interval`, within an octave.
+fn letter_steps(interval: Interval) -> u8 {
+    match interval.semitones() % Interval::OCTAVE.semitones() {
+        0 => 0,
+        1 | 2 => 1,
+        3 | 4 => 2,
+        5 | 6 => 3,
+        7 => 4,
+        8 | 9 => 5,
+        _ => 6,
+    }
 }
 
 impl From<Natural> for Note {
@@ -102,25 +260,35 @@ impl fmt::Display for Note {
 impl FromStr for Note {
     type Err = Error;
 
+    /// Parses a natural followed by an optional accidental, written in ASCII or Unicode.
+    /// ```
+    /// use staff::{Natural, Note};
+    ///
+    /// assert_eq!("Bb".parse::<Note>().unwrap(), Note::flat(Natural::B));
+    /// assert_eq!("F♯♯".parse::<Note>().unwrap(), Note::double_sharp(Natural::F));
+    /// assert!("H".parse::<Note>().is_err());
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut chars = s.chars();
         let natural: Natural = if let Some(c) = chars.next() {
-            c.try_into().unwrap()
+            c.try_into().map_err(Error::InvalidNatural)?
         } else {
             return Err(Error::Empty);
         };
 
         let accidental = match chars.next() {
-            Some('b') => match chars.next() {
-                Some('b') => Accidental::DoubleFlat,
+            Some('b' | '♭') => match chars.next() {
+                Some('b' | '♭') => Accidental::DoubleFlat,
                 Some(c) => return Err(c.into()),
                 None => Accidental::Flat,
             },
-            Some('#') => match chars.next() {
-                Some('#') => Accidental::DoubleSharp,
+            Some('#' | '♯') => match chars.next() {
+                Some('#' | '♯') => Accidental::DoubleSharp,
                 Some(c) => return Err(c.into()),
                 None => Accidental::Sharp,
             },
+            Some('𝄫') => Accidental::DoubleFlat,
+            Some('𝄪') => Accidental::DoubleSharp,
             Some(c) => return Err(c.into()),
             None => Accidental::Natural,
         };
@@ -133,6 +301,7 @@ impl FromStr for Note {
 pub enum Error {
     Empty,
     Invalid(char),
+    InvalidNatural(char),
 }
 
 impl fmt::Display for Error {
@@ -140,6 +309,7 @@ impl fmt::Display for Error {
         match self {
             Self::Empty => f.write_str("Empty note input"),
             Self::Invalid(c) => write!(f, "Invalid character `{}`", c),
+            Self::InvalidNatural(c) => write!(f, "Invalid note name `{}`, expected A through G", c),
         }
     }
 }
@@ -1,9 +1,10 @@
 //!Pitch classes
 
-use crate::note::{Accidental, Note};
-use crate::{midi::MidiNote, Interval, Natural};
+use crate::note::{self, Accidental, AccidentalPreference, Note};
+use crate::scale::ScaleIntervals;
+use crate::{midi::MidiNote, Interval, Key, Natural, SignedInterval};
 use core::ops::{Add, Sub};
-use core::{fmt, mem};
+use core::{fmt, mem, str::FromStr};
 
 /// Pitch class that can be found on the chromatic scale.
 #[repr(u8)]
@@ -53,20 +54,60 @@ impl Pitch {
     }
 
     pub const fn sub_interval(self, interval: Interval) -> Self {
-        let byte = if self as u8 > interval.semitones() {
-            self as u8 - interval.semitones()
-        } else {
-            self as u8 + Self::B as u8 + 1 - interval.semitones()
-        };
-        Self::from_byte(byte)
+        let octave = Self::B as u8 + 1;
+        Self::from_byte(self as u8 + octave - interval.semitones() % octave)
+    }
+
+    /// Returns the pitch `semitones` above `self`, wrapping around the octave.
+    /// ```
+    /// use staff::Pitch;
+    ///
+    /// assert_eq!(Pitch::A.above(3), Pitch::C);
+    /// assert_eq!(Pitch::B.above(15), Pitch::D);
+    /// ```
+    pub const fn above(self, semitones: u8) -> Self {
+        let octave = Self::B as u8 + 1;
+        Self::from_byte(self as u8 + semitones % octave)
+    }
+
+    /// Returns the pitch `semitones` below `self`, wrapping around the octave.
+    /// ```
+    /// use staff::Pitch;
+    ///
+    /// assert_eq!(Pitch::C.below(3), Pitch::A);
+    /// assert_eq!(Pitch::C.below(15), Pitch::A);
+    /// ```
+    pub const fn below(self, semitones: u8) -> Self {
+        let octave = Self::B as u8 + 1;
+        Self::from_byte(self as u8 + octave - semitones % octave)
+    }
+
+    /// Apply an interval to `self` in the interval's direction.
+    /// ```
+    /// use staff::{Pitch, SignedInterval};
+    ///
+    /// assert_eq!(Pitch::C.apply_interval(SignedInterval::ascending(4)), Pitch::E);
+    /// assert_eq!(Pitch::C.apply_interval(SignedInterval::descending(3)), Pitch::A);
+    /// ```
+    pub const fn apply_interval(self, interval: SignedInterval) -> Self {
+        let octave = Self::B as i32 + 1;
+        Self::from_byte((self as i32 + interval.semitones).rem_euclid(octave) as u8)
     }
 
     pub const fn into_byte(self) -> u8 {
         self as _
     }
 
+    /// Returns the ascending interval from `rhs` up to `self`, wrapping at the octave.
+    /// ```
+    /// use staff::{Interval, Pitch};
+    ///
+    /// assert_eq!(Pitch::E - Pitch::C, Interval::MAJOR_THIRD);
+    /// assert_eq!(Pitch::C - Pitch::B, Interval::MINOR_SECOND);
+    /// ```
     pub const fn sub(self, rhs: Self) -> Interval {
-        Interval::new(self as u8 - rhs as u8)
+        let octave = Self::B as u8 + 1;
+        Interval::new((self as u8 + octave - rhs as u8) % octave)
     }
 
     pub fn transpose(self, key: Pitch, to: Pitch) -> Pitch {
@@ -78,6 +119,93 @@ impl Pitch {
         Interval::new((self as u8).abs_diff(rhs as u8))
     }
 
+    /// Returns this pitch spelled as a note with the given accidental preference.
+    ///
+    /// Natural pitches are always spelled without an accidental,
+    /// unless a key-based preference spells them otherwise.
+    /// ```
+    /// use staff::{Natural, Note, Pitch};
+    /// use staff::note::AccidentalPreference;
+    ///
+    /// assert_eq!(Pitch::CSharp.to_note(AccidentalPreference::Sharps), Note::sharp(Natural::C));
+    /// assert_eq!(Pitch::CSharp.to_note(AccidentalPreference::Flats), Note::flat(Natural::D));
+    /// assert_eq!(Pitch::E.to_note(AccidentalPreference::Flats), Note::E);
+    /// ```
+    pub fn to_note(self, preference: AccidentalPreference) -> Note {
+        Note::from_pitch_with(self, preference)
+    }
+
+    /// Returns the 0-indexed degree of this pitch in the major scale of `key`,
+    /// or `None` if this pitch is not diatonic to `key`.
+    /// ```
+    /// use staff::{Key, Pitch};
+    ///
+    /// let key = Key::major(Pitch::D);
+    /// assert_eq!(Pitch::D.diatonic_index(key), Some(0));
+    /// assert_eq!(Pitch::FSharp.diatonic_index(key), Some(2));
+    /// assert_eq!(Pitch::F.diatonic_index(key), None);
+    /// ```
+    pub fn diatonic_index(self, key: Key) -> Option<u8> {
+        let interval = self - key.tonic();
+        ScaleIntervals::major()
+            .into_iter()
+            .position(|degree| degree == interval)
+            .map(|idx| idx as u8)
+    }
+
+    /// Returns the five pitches spelled with two different naturals,
+    /// along with their sharp and flat spellings.
+    /// ```
+    /// use staff::{Natural, Note, Pitch};
+    ///
+    /// let pairs = Pitch::enharmonic_pairs();
+    /// assert_eq!(pairs[0], (Pitch::CSharp, Note::sharp(Natural::C), Note::flat(Natural::D)));
+    /// assert!(pairs.iter().all(|(pitch, sharp, flat)| {
+    ///     Pitch::from(*sharp) == *pitch && Pitch::from(*flat) == *pitch
+    /// }));
+    /// ```
+    pub const fn enharmonic_pairs() -> [(Self, Note, Note); 5] {
+        [
+            (
+                Self::CSharp,
+                Note::sharp(Natural::C),
+                Note::flat(Natural::D),
+            ),
+            (
+                Self::DSharp,
+                Note::sharp(Natural::D),
+                Note::flat(Natural::E),
+            ),
+            (
+                Self::FSharp,
+                Note::sharp(Natural::F),
+                Note::flat(Natural::G),
+            ),
+            (
+                Self::GSharp,
+                Note::sharp(Natural::G),
+                Note::flat(Natural::A),
+            ),
+            (
+                Self::ASharp,
+                Note::sharp(Natural::A),
+                Note::flat(Natural::B),
+            ),
+        ]
+    }
+
+    /// Returns true if this pitch is one of the [`enharmonic_pairs`](Self::enharmonic_pairs),
+    /// which need a key to be spelled correctly.
+    /// ```
+    /// use staff::Pitch;
+    ///
+    /// assert!(Pitch::GSharp.is_enharmonic_pitch());
+    /// assert!(!Pitch::G.is_enharmonic_pitch());
+    /// ```
+    pub fn is_enharmonic_pitch(self) -> bool {
+        !self.is_natural()
+    }
+
     pub fn is_natural(self) -> bool {
         match self {
             Self::C | Self::D | Self::E | Self::F | Self::G | Self::A | Self::B => true,
@@ -155,6 +283,25 @@ impl Sub<Interval> for Pitch {
     }
 }
 
+impl FromStr for Pitch {
+    type Err = note::Error;
+
+    /// Parses a pitch from its name with an optional sharp or flat.
+    /// ```
+    /// use staff::Pitch;
+    ///
+    /// assert_eq!("C#".parse::<Pitch>().unwrap(), Pitch::CSharp);
+    /// assert_eq!("Db".parse::<Pitch>().unwrap(), Pitch::CSharp);
+    /// assert_eq!("B♭".parse::<Pitch>().unwrap(), Pitch::ASharp);
+    ///
+    /// let error = "H".parse::<Pitch>().unwrap_err();
+    /// assert_eq!(error.to_string(), "Invalid note name `H`, expected A through G");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Note>().map(Self::from)
+    }
+}
+
 impl fmt::Display for Pitch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -0,0 +1,23 @@
+use crate::Pitch;
+
+/// How two scales are related, created with [`Scale::compare`](super::Scale::compare).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScaleComparison {
+    /// True if both scales have the same root and intervals
+    pub is_identical: bool,
+    /// True if both scales have the same intervals from a root of the same pitch,
+    /// even if the roots are spelled differently
+    pub is_enharmonic: bool,
+    /// True if both scales have the same intervals from roots of different pitches
+    pub is_transposition: bool,
+    /// The semitones up from the first root to the second, if both scales have the same intervals
+    pub transposition_interval: Option<u8>,
+    /// The 1-indexed degree of the second scale the first one is a mode of
+    pub is_mode_of: Option<u8>,
+    /// True if every pitch of the first scale is in the second one
+    pub is_subset_of: bool,
+    /// True if every pitch of the second scale is in the first one
+    pub is_superset_of: bool,
+    /// The pitches of the first scale also in the second, in the order of the first scale
+    pub shared_pitches: Vec<Pitch>,
+}
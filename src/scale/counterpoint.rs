@@ -0,0 +1,179 @@
+use crate::Pitch;
+
+/// Intervals in semitones that are consonant against the cantus firmus.
+const CONSONANCES: [i32; 6] = [0, 3, 4, 7, 8, 9];
+
+/// The highest note of the countermelody, in semitones above the cantus firmus.
+const MAX_INTERVAL: i32 = 16;
+
+/// The number of partial countermelodies to try before giving up.
+const MAX_STEPS: usize = 10_000;
+
+/// Backtracking search for a first species countermelody above `cantus`.
+///
+/// Each note is chosen from the consonances of `pitches` above the cantus firmus,
+/// preferring contrary motion and small steps.
+pub(crate) fn generate(pitches: &[Pitch], cantus: &[Pitch], tonic: Pitch) -> Vec<Pitch> {
+    if cantus.is_empty() {
+        return Vec::new();
+    }
+
+    // Place each note of the cantus firmus as close as possible to the previous one
+    let mut notes: Vec<i32> = Vec::with_capacity(cantus.len());
+    for pitch in cantus {
+        let class = pitch.into_byte() as i32;
+        let note = match notes.last() {
+            Some(&prev) => {
+                let up = (class - prev).rem_euclid(12);
+                if up <= 6 {
+                    prev + up
+                } else {
+                    prev + up - 12
+                }
+            }
+            None => 60 + class,
+        };
+        notes.push(note);
+    }
+
+    let last = notes.len() - 1;
+    let domains: Vec<Vec<i32>> = notes
+        .iter()
+        .enumerate()
+        .map(|(idx, &note)| {
+            let candidates = (note..=note + MAX_INTERVAL).filter(|&candidate| {
+                let interval = candidate - note;
+                let is_in_scale = pitches
+                    .iter()
+                    .any(|pitch| pitch.into_byte() as i32 == candidate.rem_euclid(12));
+                let is_allowed = if idx == 0 {
+                    is_perfect(interval)
+                } else if idx == last {
+                    interval % 12 == 0
+                } else {
+                    interval != 0 && CONSONANCES.contains(&(interval % 12))
+                };
+                is_in_scale && is_allowed
+            });
+
+            let mut domain: Vec<i32> = candidates.collect();
+            // End on the tonic of the key if the cantus firmus allows it
+            if idx == last && domain.iter().any(|&note| is_pitch(note, tonic)) {
+                domain.retain(|&note| is_pitch(note, tonic));
+            }
+            if idx == 0 {
+                domain.sort_by_key(|&candidate| (candidate - (note + 12)).abs());
+            }
+            domain
+        })
+        .collect();
+
+    let mut search = Search {
+        cantus: &notes,
+        domains: &domains,
+        counter: Vec::with_capacity(notes.len()),
+        steps: 0,
+    };
+    if !search.solve() {
+        return Vec::new();
+    }
+
+    search
+        .counter
+        .iter()
+        .map(|note| Pitch::from_byte(note.rem_euclid(12) as u8))
+        .collect()
+}
+
+struct Search<'a> {
+    cantus: &'a [i32],
+    domains: &'a [Vec<i32>],
+    counter: Vec<i32>,
+    steps: usize,
+}
+
+impl Search<'_> {
+    fn solve(&mut self) -> bool {
+        let idx = self.counter.len();
+        if idx == self.cantus.len() {
+            return true;
+        }
+        self.steps += 1;
+        if self.steps > MAX_STEPS {
+            return false;
+        }
+
+        let mut candidates: Vec<i32> = self.domains[idx]
+            .iter()
+            .copied()
+            .filter(|&note| self.is_valid(idx, note))
+            .collect();
+        if let Some(&prev) = self.counter.last() {
+            let cantus_motion = self.cantus[idx] - self.cantus[idx - 1];
+            candidates.sort_by_key(|&note| {
+                let is_contrary = (note - prev).signum() == -cantus_motion.signum();
+                (!is_contrary, (note - prev).abs())
+            });
+        }
+
+        for note in candidates {
+            self.counter.push(note);
+
+            // Only continue if the next note still has a valid choice
+            let next = idx + 1;
+            let has_next = next == self.cantus.len()
+                || self.domains[next]
+                    .iter()
+                    .any(|&candidate| self.is_valid(next, candidate));
+            if has_next && self.solve() {
+                return true;
+            }
+            self.counter.pop();
+        }
+        false
+    }
+
+    /// Returns true if `note` can follow the current countermelody at `idx`.
+    fn is_valid(&self, idx: usize, note: i32) -> bool {
+        if idx == 0 {
+            return true;
+        }
+        let prev = self.counter[idx - 1];
+
+        let leap = (note - prev).abs();
+        if leap == 0 || leap == 6 || (leap > 8 && leap != 12) {
+            return false;
+        }
+
+        // Avoid parallel and hidden fifths and octaves
+        let cantus_motion = self.cantus[idx] - self.cantus[idx - 1];
+        let is_similar = (note - prev).signum() == cantus_motion.signum();
+        if is_similar && is_perfect(note - self.cantus[idx]) {
+            return false;
+        }
+
+        // Avoid moving in the same direction as the cantus firmus three times in a row
+        let is_similar_at = |idx: usize| {
+            let motion = self.counter[idx] - self.counter[idx - 1];
+            motion.signum() == (self.cantus[idx] - self.cantus[idx - 1]).signum()
+        };
+        if is_similar && idx >= 3 && is_similar_at(idx - 1) && is_similar_at(idx - 2) {
+            return false;
+        }
+
+        // Approach the final note by step or oblique motion
+        if idx == self.cantus.len() - 1 && leap > 2 && cantus_motion != 0 {
+            return false;
+        }
+
+        true
+    }
+}
+
+fn is_perfect(interval: i32) -> bool {
+    matches!(interval % 12, 0 | 7)
+}
+
+fn is_pitch(note: i32, pitch: Pitch) -> bool {
+    note.rem_euclid(12) == pitch.into_byte() as i32
+}
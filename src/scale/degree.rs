@@ -1,6 +1,6 @@
 use crate::{
     midi::MidiNote,
-    note::{Accidental, Note},
+    note::{AccidentalPreference, Note},
     pitch::Pitch,
     Interval, Natural,
 };
@@ -24,28 +24,19 @@ impl Degree for Note {
         let root: Pitch = self.into();
         let pitch = root + interval;
 
-        let natural_pitch = Pitch::from(*state);
         let natural = *state;
         *state = *state + 1u8;
 
-        let accidental = if pitch >= natural_pitch {
-            match pitch - natural_pitch {
-                Interval::UNISON => Accidental::Natural,
-                Interval::MINOR_SECOND => Accidental::Sharp,
-                Interval::MAJOR_SECOND => Accidental::DoubleSharp,
-                Interval::MAJOR_SEVENTH => Accidental::Flat,
-                _ => unimplemented!(),
-            }
-        } else {
-            match natural_pitch - pitch {
-                Interval::MINOR_SECOND => Accidental::Flat,
-                Interval::MAJOR_SECOND => Accidental::DoubleFlat,
-                Interval::MAJOR_SEVENTH => Accidental::Sharp,
-                _ => unimplemented!(),
-            }
-        };
-
-        Self::new(natural, accidental)
+        let note = Self::from_pitch_with(pitch, AccidentalPreference::Sharps);
+        note.respell_as(natural).unwrap_or_else(|| {
+            // Fall back to the nearest spelling to avoid triple accidentals
+            let preference = if pitch - Pitch::from(natural) <= Interval::TRITONE {
+                AccidentalPreference::Sharps
+            } else {
+                AccidentalPreference::Flats
+            };
+            Self::from_pitch_with(pitch, preference)
+        })
     }
 }
 
@@ -62,6 +62,76 @@ impl ScaleIntervals {
         ])
     }
 
+    pub fn major_pentatonic() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MAJOR_SECOND,
+            Interval::MAJOR_THIRD,
+            Interval::PERFECT_FIFTH,
+            Interval::MAJOR_SIXTH,
+        ])
+    }
+
+    pub fn minor_pentatonic() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MINOR_THIRD,
+            Interval::PERFECT_FOURTH,
+            Interval::PERFECT_FIFTH,
+            Interval::MINOR_SEVENTH,
+        ])
+    }
+
+    pub fn whole_tone() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MAJOR_SECOND,
+            Interval::MAJOR_THIRD,
+            Interval::TRITONE,
+            Interval::MINOR_SIXTH,
+            Interval::MINOR_SEVENTH,
+        ])
+    }
+
+    /// Whole-half diminished intervals.
+    pub fn diminished() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MAJOR_SECOND,
+            Interval::MINOR_THIRD,
+            Interval::PERFECT_FOURTH,
+            Interval::TRITONE,
+            Interval::MINOR_SIXTH,
+            Interval::MAJOR_SIXTH,
+            Interval::MAJOR_SEVENTH,
+        ])
+    }
+
+    /// Symmetrical augmented intervals, alternating minor thirds and half steps.
+    pub fn symmetrical_augmented() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MINOR_THIRD,
+            Interval::MAJOR_THIRD,
+            Interval::PERFECT_FIFTH,
+            Interval::MINOR_SIXTH,
+            Interval::MAJOR_SEVENTH,
+        ])
+    }
+
+    /// Double harmonic intervals, with augmented seconds above the second and sixth degrees.
+    pub fn double_harmonic() -> Self {
+        Self::from_iter([
+            Interval::UNISON,
+            Interval::MINOR_SECOND,
+            Interval::MAJOR_THIRD,
+            Interval::PERFECT_FOURTH,
+            Interval::PERFECT_FIFTH,
+            Interval::MINOR_SIXTH,
+            Interval::MAJOR_SEVENTH,
+        ])
+    }
+
     pub fn dorian() -> Self {
         Self::from_iter([
             Interval::UNISON,
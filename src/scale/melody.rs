@@ -0,0 +1,104 @@
+use crate::{rng::SplitMix, Pitch};
+
+/// Overall shape of a generated melody.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContourType {
+    /// Rise towards the middle of the melody and fall back down.
+    Arch,
+    /// Alternate between rising and falling every few notes.
+    Wave,
+    /// Move in either direction.
+    Free,
+}
+
+/// Options for generating a melody from a scale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MelodyOptions {
+    /// Relative chance of moving to a neighboring scale degree.
+    pub stepwise_weight: f32,
+    /// Relative chance of leaping a third or fourth.
+    pub leap_weight: f32,
+    pub contour: ContourType,
+}
+
+impl Default for MelodyOptions {
+    fn default() -> Self {
+        Self {
+            stepwise_weight: 0.8,
+            leap_weight: 0.2,
+            contour: ContourType::Arch,
+        }
+    }
+}
+
+/// Random walk over the degrees of a scale that starts and ends on the tonic.
+pub(crate) fn generate(
+    pitches: &[Pitch],
+    length: usize,
+    seed: u64,
+    options: MelodyOptions,
+) -> Vec<Pitch> {
+    let len = pitches.len() as i32;
+    if len == 0 || length == 0 {
+        return Vec::new();
+    }
+
+    let mut rng = SplitMix(seed);
+    let mut degree = 0;
+    let mut direction = 0;
+    let mut run = 0;
+    let mut melody = Vec::with_capacity(length);
+    melody.push(pitches[0]);
+
+    for idx in 1..length.saturating_sub(1) {
+        let remaining = (length - 1 - idx) as i32;
+        let tonic = (degree as f32 / len as f32).round() as i32 * len;
+
+        let up_chance = match options.contour {
+            ContourType::Arch if idx < length / 2 => 0.7,
+            ContourType::Arch => 0.3,
+            ContourType::Wave if (idx / 4) % 2 == 0 => 0.7,
+            ContourType::Wave => 0.3,
+            ContourType::Free => 0.5,
+        };
+
+        let motion = if (tonic - degree).abs() > remaining {
+            (tonic - degree).signum()
+        } else {
+            let total = options.stepwise_weight + options.leap_weight;
+            let size = if rng.next_f32() * total < options.stepwise_weight {
+                1
+            } else {
+                2 + (rng.next_f32() < 0.5) as i32
+            };
+
+            // Turn around after three moves in the same direction
+            let is_up = if run >= 3 {
+                direction < 0
+            } else {
+                rng.next_f32() < up_chance
+            };
+            if is_up {
+                size
+            } else {
+                -size
+            }
+        };
+
+        if motion.signum() == direction {
+            run += 1;
+        } else {
+            direction = motion.signum();
+            run = 1;
+        }
+        degree += motion;
+        melody.push(pitches[degree.rem_euclid(len) as usize]);
+    }
+
+    if length > 1 {
+        melody.push(pitches[0]);
+    }
+    melody
+}
@@ -1,7 +1,18 @@
 //! Scales with iterators
 
-use crate::Interval;
-use core::ops::Add;
+use crate::{midi::MidiNote, set::PitchSet, Chord, Interval, Key, Pitch};
+use core::{fmt, ops::Add};
+
+#[cfg(feature = "std")]
+use crate::{
+    chord::{Cadence, RomanNumeral, TriadQuality},
+    midi::{
+        message::{Message, NoteMessage},
+        MidiFile, Octave, TimedMessage,
+    },
+    note::Accidental,
+    time::{Duration, DurationKind, Tempo, TimeSignature},
+};
 
 mod degree;
 pub use degree::Degree;
@@ -12,6 +23,23 @@ pub use intervals::ScaleIntervals;
 mod diatonic;
 pub use diatonic::{Diatonic, DiatonicScale};
 
+#[cfg(feature = "std")]
+mod comparison;
+#[cfg(feature = "std")]
+pub use comparison::ScaleComparison;
+
+#[cfg(feature = "std")]
+mod counterpoint;
+
+#[cfg(feature = "std")]
+mod melody;
+#[cfg(feature = "std")]
+pub use melody::{ContourType, MelodyOptions};
+
+mod scale_type;
+pub use scale_type::{PentatonicType, ScaleType};
+
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scale<T, U> {
     root: T,
@@ -22,6 +50,14 @@ impl<T, U> Scale<T, U> {
     pub fn new(root: T, intervals: U) -> Self {
         Self { root, intervals }
     }
+
+    /// Returns the root of this scale.
+    pub fn root(&self) -> T
+    where
+        T: Clone,
+    {
+        self.root.clone()
+    }
 }
 
 impl<T, U> Scale<T, Diatonic<T, U>>
@@ -37,6 +73,1370 @@ impl<T> Scale<T, ScaleIntervals> {
     pub fn blues(root: T) -> Self {
         Self::new(root, ScaleIntervals::blues())
     }
+
+    pub fn major_pentatonic(root: T) -> Self {
+        Self::new(root, ScaleIntervals::major_pentatonic())
+    }
+
+    pub fn minor_pentatonic(root: T) -> Self {
+        Self::new(root, ScaleIntervals::minor_pentatonic())
+    }
+
+    pub fn whole_tone(root: T) -> Self {
+        Self::new(root, ScaleIntervals::whole_tone())
+    }
+
+    pub fn diminished(root: T) -> Self {
+        Self::new(root, ScaleIntervals::diminished())
+    }
+
+    /// Returns the symmetrical augmented scale on `root`,
+    /// with steps of 3, 1, 3, 1, 3 and 1 semitones.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let pitches: Vec<_> = Scale::symmetrical_augmented(Pitch::C).collect();
+    /// assert_eq!(
+    ///     pitches,
+    ///     [Pitch::C, Pitch::DSharp, Pitch::E, Pitch::G, Pitch::GSharp, Pitch::B]
+    /// );
+    /// ```
+    pub fn symmetrical_augmented(root: T) -> Self {
+        Self::new(root, ScaleIntervals::symmetrical_augmented())
+    }
+
+    /// Returns the double harmonic (Byzantine) scale on `root`,
+    /// with steps of 1, 3, 1, 2, 1, 3 and 1 semitones.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let pitches: Vec<_> = Scale::double_harmonic(Pitch::C).collect();
+    /// assert_eq!(
+    ///     pitches,
+    ///     [
+    ///         Pitch::C,
+    ///         Pitch::CSharp,
+    ///         Pitch::E,
+    ///         Pitch::F,
+    ///         Pitch::G,
+    ///         Pitch::GSharp,
+    ///         Pitch::B
+    ///     ]
+    /// );
+    /// ```
+    pub fn double_harmonic(root: T) -> Self {
+        Self::new(root, ScaleIntervals::double_harmonic())
+    }
+}
+
+impl Scale<Pitch, ScaleIntervals> {
+    /// Returns the seven modes of the harmonic minor scale on `root`: Harmonic Minor,
+    /// Locrian ♮6, Ionian ♯5, Dorian ♯4, Phrygian Dominant, Lydian ♯2 and Super Locrian ♭♭7.
+    ///
+    /// Each mode starts on its degree of the harmonic minor scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let modes = Scale::harmonic_minor_modes(Pitch::A);
+    /// assert_eq!(modes[0].root(), Pitch::A);
+    ///
+    /// // Phrygian dominant
+    /// let pitches: Vec<_> = modes[4].clone().collect();
+    /// assert_eq!(
+    ///     pitches,
+    ///     [Pitch::E, Pitch::F, Pitch::GSharp, Pitch::A, Pitch::B, Pitch::C, Pitch::D]
+    /// );
+    /// assert_eq!(modes[6].root(), Pitch::GSharp);
+    /// ```
+    pub fn harmonic_minor_modes(root: Pitch) -> [Self; 7] {
+        Self::modes_of(root, ScaleIntervals::harmonic_minor())
+    }
+
+    /// Returns the seven modes of the jazz melodic minor scale on `root`: Jazz Minor,
+    /// Dorian ♭2, Lydian Augmented, Lydian Dominant, Mixolydian ♭6, Locrian ♮2 and Super Locrian.
+    ///
+    /// Each mode starts on its degree of the melodic minor scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let modes = Scale::melodic_minor_modes(Pitch::C);
+    /// assert_eq!(modes[3].root(), Pitch::F);
+    /// assert_eq!(modes[3].clone().mode_name(), Some("Lydian Dominant"));
+    ///
+    /// assert_eq!(modes[6].root(), Pitch::B);
+    /// assert_eq!(modes[6].clone().mode_name(), Some("Super Locrian"));
+    /// ```
+    pub fn melodic_minor_modes(root: Pitch) -> [Self; 7] {
+        Self::modes_of(root, ScaleIntervals::melodic_minor())
+    }
+
+    fn modes_of(root: Pitch, intervals: ScaleIntervals) -> [Self; 7] {
+        let scale = Self::new(root, intervals);
+        core::array::from_fn(|idx| scale.clone().mode_at(idx as u8 + 1).unwrap())
+    }
+
+    /// Returns the scales that best fit the given pitches, trying every root of every [`ScaleType`].
+    ///
+    /// Each scale is scored by the fraction of the pitches it contains, so scales that contain
+    /// every pitch score 1.
+    /// Scales with the same score are sorted by how many of their own notes were given.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let pitches = [Pitch::G, Pitch::A, Pitch::B, Pitch::C, Pitch::D, Pitch::E, Pitch::FSharp];
+    /// let scales = Scale::detect(&pitches);
+    ///
+    /// let (scale, score) = scales[0].clone();
+    /// assert_eq!(score, 1.);
+    /// assert_eq!(scale.root(), Pitch::G);
+    /// assert_eq!(scale.scale_type(), ScaleType::Major);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn detect(pitches: &[Pitch]) -> Vec<(Self, f32)> {
+        let pitches: PitchSet = pitches.iter().copied().collect();
+        let count = pitches.count();
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let mut scales: Vec<_> = ScaleType::ALL
+            .into_iter()
+            .filter(|scale_type| ScaleType::from(scale_type.intervals()) == *scale_type)
+            .flat_map(|scale_type| {
+                (0..=Pitch::B.into_byte())
+                    .map(move |byte| Self::new(Pitch::from_byte(byte), scale_type.intervals()))
+            })
+            .filter_map(|scale| {
+                let notes: PitchSet = scale.clone().collect();
+                let matched = (notes.bits & pitches.bits).count_ones();
+                if matched == 0 {
+                    return None;
+                }
+
+                let score = matched as f32 / count as f32;
+                let coverage = matched as f32 / notes.count() as f32;
+                Some((scale, score, coverage))
+            })
+            .collect();
+
+        scales.sort_by(|(_, a, a_coverage), (_, b, b_coverage)| {
+            b.total_cmp(a).then(b_coverage.total_cmp(a_coverage))
+        });
+        scales
+            .into_iter()
+            .map(|(scale, score, _)| (scale, score))
+            .collect()
+    }
+}
+
+impl<T, U> Scale<T, U>
+where
+    T: Clone + Into<Pitch>,
+    Self: Iterator + Clone,
+    <Self as Iterator>::Item: Into<Pitch>,
+{
+    /// Returns the type of this scale by comparing its intervals to all known scale types.
+    /// ```
+    /// use staff::{Natural, Note, Pitch, Scale};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let scale = Scale::dorian(Note::from(Natural::D));
+    /// assert_eq!(scale.scale_type(), ScaleType::Dorian);
+    ///
+    /// let scale = Scale::blues(Pitch::A);
+    /// assert_eq!(scale.scale_type(), ScaleType::Blues);
+    /// ```
+    pub fn scale_type(self) -> ScaleType {
+        ScaleType::from(self.pitch_intervals())
+    }
+
+    /// Generate a melody of `length` pitches that starts and ends on the tonic,
+    /// using the default [`MelodyOptions`].
+    ///
+    /// The same seed always generates the same melody.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let melody = Scale::major(Pitch::C).generate_melody(8, 42);
+    ///
+    /// assert_eq!(melody.len(), 8);
+    /// assert_eq!(melody.first(), Some(&Pitch::C));
+    /// assert_eq!(melody.last(), Some(&Pitch::C));
+    /// assert_eq!(melody, Scale::major(Pitch::C).generate_melody(8, 42));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn generate_melody(self, length: usize, seed: u64) -> Vec<Pitch> {
+        self.generate_melody_with(length, seed, MelodyOptions::default())
+    }
+
+    /// Generate a melody of `length` pitches with a weighted random walk over this scale.
+    #[cfg(feature = "std")]
+    pub fn generate_melody_with(
+        self,
+        length: usize,
+        seed: u64,
+        options: MelodyOptions,
+    ) -> Vec<Pitch> {
+        let pitches: Vec<Pitch> = self.map(Into::into).collect();
+        melody::generate(&pitches, length, seed, options)
+    }
+
+    /// Generate a first species countermelody above `melody` from the notes of this scale,
+    /// or an empty melody if none can be found.
+    ///
+    /// Each note is a consonance against the melody, starting on a perfect consonance
+    /// and ending on the tonic of `key` (or an octave above the melody) by step or oblique motion.
+    /// Parallel and hidden fifths and octaves and leaps of a tritone are avoided,
+    /// and contrary motion is preferred.
+    /// ```
+    /// use staff::{Key, Pitch, Scale};
+    ///
+    /// let melody = [Pitch::C, Pitch::D, Pitch::F, Pitch::E, Pitch::D, Pitch::C];
+    /// let counter = Scale::major(Pitch::C).generate_countermelody(&melody, Key::major(Pitch::C));
+    ///
+    /// assert_eq!(counter, [Pitch::C, Pitch::B, Pitch::A, Pitch::C, Pitch::B, Pitch::C]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn generate_countermelody(self, melody: &[Pitch], key: Key) -> Vec<Pitch> {
+        let pitches: Vec<Pitch> = self.map(Into::into).collect();
+        counterpoint::generate(&pitches, melody, key.tonic())
+    }
+
+    /// Returns a MIDI file that plays this scale from `octave`,
+    /// ascending, descending, or up and back down.
+    ///
+    /// Each note lasts for `duration` and is played with `velocity`.
+    /// ```
+    /// use staff::{midi, Pitch, Scale};
+    /// use staff::midi::{message::Message, Octave};
+    /// use staff::time::{DurationKind, Tempo};
+    ///
+    /// let scale = Scale::major(Pitch::C);
+    /// let file = scale.to_midi_sequence(
+    ///     Octave::FOUR,
+    ///     true,
+    ///     true,
+    ///     DurationKind::Eigth.into(),
+    ///     Tempo::new(100.),
+    ///     90,
+    /// );
+    ///
+    /// // C4 up to C5 and back down
+    /// assert_eq!(file.messages.len(), 15 * 2);
+    ///
+    /// let top = &file.messages[14];
+    /// assert_eq!(top.tick, 7 * 240);
+    /// let Message::NoteOn(msg) = &top.message else { panic!() };
+    /// assert_eq!(msg.note, midi!(C, 5));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_midi_sequence(
+        self,
+        octave: Octave,
+        ascending: bool,
+        descending: bool,
+        duration: Duration,
+        tempo: Tempo,
+        velocity: u8,
+    ) -> MidiFile {
+        let start = MidiNote::new(self.root.clone().into(), octave);
+        let mut notes: Vec<_> = self
+            .pitch_intervals()
+            .chain([Interval::OCTAVE])
+            .map(|interval| start + interval)
+            .collect();
+
+        match (ascending, descending) {
+            (true, true) => {
+                let back: Vec<_> = notes.iter().rev().skip(1).copied().collect();
+                notes.extend(back);
+            }
+            (true, false) => {}
+            (false, true) => notes.reverse(),
+            (false, false) => notes.clear(),
+        }
+
+        let mut file = MidiFile::new(tempo, TimeSignature::new(DurationKind::Quarter, 4));
+        let length = (duration.beats(4) * file.ticks_per_beat as f64).round() as u32;
+        file.messages = notes
+            .into_iter()
+            .enumerate()
+            .flat_map(|(idx, note)| {
+                let tick = idx as u32 * length;
+                [
+                    TimedMessage {
+                        tick,
+                        message: Message::NoteOn(NoteMessage { note, velocity }),
+                    },
+                    TimedMessage {
+                        tick: tick + length,
+                        message: Message::NoteOff(NoteMessage { note, velocity: 0 }),
+                    },
+                ]
+            })
+            .collect();
+        file
+    }
+
+    /// Returns the major or minor pentatonic scale on this scale's root if it has every note of one,
+    /// preferring major.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::scale::{PentatonicType, ScaleType};
+    ///
+    /// let pentatonic = Scale::major(Pitch::G).pentatonic_subset().unwrap();
+    /// assert_eq!(pentatonic.scale_type(), ScaleType::Pentatonic(PentatonicType::Major));
+    ///
+    /// let pentatonic = Scale::natural_minor(Pitch::A).pentatonic_subset().unwrap();
+    /// assert_eq!(pentatonic.scale_type(), ScaleType::Pentatonic(PentatonicType::Minor));
+    ///
+    /// assert!(Scale::locrian(Pitch::B).pentatonic_subset().is_none());
+    /// ```
+    pub fn pentatonic_subset(self) -> Option<Scale<Pitch, ScaleIntervals>> {
+        let intervals = self.pitch_intervals();
+        [
+            ScaleIntervals::major_pentatonic(),
+            ScaleIntervals::minor_pentatonic(),
+        ]
+        .into_iter()
+        .find(|pentatonic| pentatonic.bits & !intervals.bits == 0)
+        .map(|pentatonic| Scale::new(self.root.into(), pentatonic))
+    }
+
+    /// Returns the minor pentatonic scale with the same notes as the major pentatonic on this
+    /// scale's root, if this is a major or major pentatonic scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::scale::{PentatonicType, ScaleType};
+    ///
+    /// let scale = Scale::major(Pitch::C).relative_minor_pentatonic().unwrap();
+    /// assert_eq!(scale.root(), Pitch::A);
+    /// assert_eq!(scale.scale_type(), ScaleType::Pentatonic(PentatonicType::Minor));
+    ///
+    /// assert!(Scale::dorian(Pitch::D).relative_minor_pentatonic().is_none());
+    /// ```
+    pub fn relative_minor_pentatonic(self) -> Option<Scale<Pitch, ScaleIntervals>> {
+        let intervals = self.pitch_intervals();
+        if intervals != ScaleIntervals::major() && intervals != ScaleIntervals::major_pentatonic() {
+            return None;
+        }
+
+        let root = self.starting_pitch() + Interval::MAJOR_SIXTH;
+        Some(Scale::minor_pentatonic(root))
+    }
+
+    /// Returns the major pentatonic scale with the same notes as the minor pentatonic on this
+    /// scale's root, if this is a natural minor or minor pentatonic scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::scale::{PentatonicType, ScaleType};
+    ///
+    /// let scale = Scale::minor_pentatonic(Pitch::E).relative_major_pentatonic().unwrap();
+    /// assert_eq!(scale.root(), Pitch::G);
+    /// assert_eq!(scale.scale_type(), ScaleType::Pentatonic(PentatonicType::Major));
+    /// ```
+    pub fn relative_major_pentatonic(self) -> Option<Scale<Pitch, ScaleIntervals>> {
+        let intervals = self.pitch_intervals();
+        if intervals != ScaleIntervals::natural_minor()
+            && intervals != ScaleIntervals::minor_pentatonic()
+        {
+            return None;
+        }
+
+        let root = self.starting_pitch() + Interval::MINOR_THIRD;
+        Some(Scale::major_pentatonic(root))
+    }
+
+    /// Returns true if this scale and `other` are a major and minor pentatonic scale
+    /// with the same notes.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let a_minor = Scale::minor_pentatonic(Pitch::A);
+    /// assert!(a_minor.clone().is_relative_pentatonic_of(Scale::major_pentatonic(Pitch::C)));
+    /// assert!(!a_minor.is_relative_pentatonic_of(Scale::major_pentatonic(Pitch::A)));
+    /// ```
+    pub fn is_relative_pentatonic_of<V, W>(self, other: Scale<V, W>) -> bool
+    where
+        V: Clone + Into<Pitch>,
+        Scale<V, W>: Iterator + Clone,
+        <Scale<V, W> as Iterator>::Item: Into<Pitch>,
+    {
+        let intervals = self.pitch_intervals();
+        let other_intervals = other.pitch_intervals();
+        let major = ScaleIntervals::major_pentatonic();
+        let minor = ScaleIntervals::minor_pentatonic();
+
+        let is_pair = (intervals == major && other_intervals == minor)
+            || (intervals == minor && other_intervals == major);
+        is_pair && self.pitch_set() == other.pitch_set()
+    }
+
+    /// Returns the mode of this scale starting on the given (1-indexed) degree.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let mode = Scale::major(Pitch::C).mode_at(2).unwrap();
+    /// assert_eq!(mode.root(), Pitch::D);
+    /// assert_eq!(mode.scale_type(), ScaleType::Dorian);
+    ///
+    /// let mode = Scale::major(Pitch::C).mode_at(5).unwrap();
+    /// assert_eq!(mode.root(), Pitch::G);
+    /// assert_eq!(mode.scale_type(), ScaleType::Mixolydian);
+    /// ```
+    pub fn mode_at(self, degree: u8) -> Result<Scale<Pitch, ScaleIntervals>, Error> {
+        let intervals = self.pitch_intervals();
+        let shift = degree
+            .checked_sub(1)
+            .and_then(|idx| intervals.clone().nth(idx as usize))
+            .ok_or(Error::InvalidDegree(degree))?;
+
+        let root = self.starting_pitch() + shift;
+        let octave = Interval::OCTAVE.semitones();
+        let intervals = intervals
+            .map(|interval| {
+                Interval::new((interval.semitones() + octave - shift.semitones()) % octave)
+            })
+            .collect();
+        Ok(Scale::new(root, intervals))
+    }
+
+    /// Returns the pitch this scale starts from.
+    /// ```
+    /// use staff::{midi, Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::major(midi!(D, 4)).starting_pitch(), Pitch::D);
+    /// ```
+    pub fn starting_pitch(self) -> Pitch {
+        self.root.into()
+    }
+
+    /// Returns the steps in semitones between each note of this scale, ending on the octave.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::major(Pitch::D).interval_pattern(), [2, 2, 1, 2, 2, 2, 1]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn interval_pattern(self) -> Vec<u8> {
+        let intervals: Vec<_> = self
+            .pitch_intervals()
+            .chain([Interval::OCTAVE])
+            .map(|interval| interval.semitones())
+            .collect();
+        intervals.windows(2).map(|pair| pair[1] - pair[0]).collect()
+    }
+
+    /// Returns the intervals that set this scale apart from the major and natural minor scales.
+    ///
+    /// These are the intervals from the root that neither scale has,
+    /// and any steps between neighboring notes wider than a whole tone.
+    /// ```
+    /// use staff::{Interval, Pitch, Scale};
+    ///
+    /// let scale = Scale::lydian(Pitch::F);
+    /// assert_eq!(scale.characteristic_interval(), [Interval::TRITONE]);
+    ///
+    /// // The augmented second between the sixth and seventh degrees
+    /// let scale = Scale::harmonic_minor(Pitch::A);
+    /// assert_eq!(scale.characteristic_interval(), [Interval::MINOR_THIRD]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn characteristic_interval(self) -> Vec<Interval> {
+        let common = ScaleIntervals::major().bits | ScaleIntervals::natural_minor().bits;
+        let intervals = self.pitch_intervals();
+
+        let mut characteristic: ScaleIntervals = intervals
+            .filter(|interval| common & (1 << interval.semitones()) == 0)
+            .collect();
+
+        let steps: Vec<_> = intervals.chain([Interval::OCTAVE]).collect();
+        for pair in steps.windows(2) {
+            let step = pair[1].semitones() - pair[0].semitones();
+            if step > Interval::MAJOR_SECOND.semitones() {
+                characteristic.push(Interval::new(step));
+            }
+        }
+
+        characteristic.collect()
+    }
+
+    /// Returns the degrees of this scale that differ from the major scale,
+    /// with the accidental that alters each one.
+    ///
+    /// Scales without seven notes have each note outside the major scale
+    /// written as a flattened degree, like the ♭3 and ♭7 of the minor pentatonic scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::note::Accidental;
+    ///
+    /// let scale = Scale::dorian(Pitch::D);
+    /// assert_eq!(scale.compare_to_major(), [(3, Accidental::Flat), (7, Accidental::Flat)]);
+    ///
+    /// let scale = Scale::lydian(Pitch::F);
+    /// assert_eq!(scale.compare_to_major(), [(4, Accidental::Sharp)]);
+    ///
+    /// let scale = Scale::minor_pentatonic(Pitch::A);
+    /// assert_eq!(scale.compare_to_major(), [(3, Accidental::Flat), (7, Accidental::Flat)]);
+    ///
+    /// let scale = Scale::blues(Pitch::A);
+    /// assert_eq!(
+    ///     scale.compare_to_major(),
+    ///     [(3, Accidental::Flat), (5, Accidental::Flat), (7, Accidental::Flat)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn compare_to_major(self) -> Vec<(u8, Accidental)> {
+        let major: Vec<_> = ScaleIntervals::major().collect();
+        let intervals: Vec<_> = self.pitch_intervals().collect();
+
+        let degrees: Vec<(u8, i8)> = if intervals.len() == major.len() {
+            intervals
+                .iter()
+                .zip(&major)
+                .enumerate()
+                .map(|(idx, (interval, major))| {
+                    let diff = interval.semitones() as i8 - major.semitones() as i8;
+                    (idx as u8 + 1, diff)
+                })
+                .collect()
+        } else {
+            intervals
+                .iter()
+                .map(|interval| {
+                    let idx = major
+                        .iter()
+                        .position(|major| major.semitones() >= interval.semitones())
+                        .unwrap_or(major.len() - 1);
+                    let diff = interval.semitones() as i8 - major[idx].semitones() as i8;
+                    (idx as u8 + 1, diff)
+                })
+                .collect()
+        };
+
+        degrees
+            .into_iter()
+            .filter_map(|(degree, diff)| {
+                let accidental = match diff {
+                    -2 => Accidental::DoubleFlat,
+                    -1 => Accidental::Flat,
+                    1 => Accidental::Sharp,
+                    2 => Accidental::DoubleSharp,
+                    _ => return None,
+                };
+                Some((degree, accidental))
+            })
+            .collect()
+    }
+
+    /// Returns the degrees raised or lowered from the major scale with the same root.
+    ///
+    /// This is the same as [`Scale::compare_to_major`].
+    /// ```
+    /// use staff::{Pitch, Scale};
+    /// use staff::note::Accidental;
+    ///
+    /// let scale = Scale::natural_minor(Pitch::A);
+    /// assert_eq!(
+    ///     scale.chromatic_alterations(),
+    ///     [(3, Accidental::Flat), (6, Accidental::Flat), (7, Accidental::Flat)]
+    /// );
+    ///
+    /// let scale = Scale::minor_pentatonic(Pitch::A);
+    /// assert_eq!(scale.chromatic_alterations(), [(3, Accidental::Flat), (7, Accidental::Flat)]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn chromatic_alterations(self) -> Vec<(u8, Accidental)> {
+        self.compare_to_major()
+    }
+
+    /// Describes this scale by how it alters the major scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let scale = Scale::natural_minor(Pitch::C);
+    /// assert_eq!(scale.describe_as_major_modification(), "major with ♭3, ♭6, ♭7");
+    ///
+    /// assert_eq!(Scale::major(Pitch::C).describe_as_major_modification(), "major");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn describe_as_major_modification(self) -> String {
+        let alterations: Vec<_> = self
+            .compare_to_major()
+            .into_iter()
+            .map(|(degree, accidental)| format!("{}{}", accidental, degree))
+            .collect();
+
+        if alterations.is_empty() {
+            String::from("major")
+        } else {
+            format!("major with {}", alterations.join(", "))
+        }
+    }
+
+    /// Returns the tensions (9ths, 11ths and 13ths) of this scale that can be added to
+    /// the seventh chord on the given (1-indexed) degree.
+    ///
+    /// A tension a half step above a chord tone is an avoid note, except that dominant
+    /// seventh chords can take a b9 above the root or a b13 above the fifth.
+    /// Scales without seven notes have no tensions.
+    /// ```
+    /// use staff::{Interval, Pitch, Scale};
+    ///
+    /// let ninth = Interval::MAJOR_NINTH;
+    /// let thirteenth = Interval::MAJOR_THIRTEENTH;
+    ///
+    /// let scale = Scale::major(Pitch::C);
+    /// assert_eq!(scale.clone().available_tensions_for_degree(5), [ninth, thirteenth]);
+    ///
+    /// let sharp_eleventh = Interval::new(18);
+    /// assert_eq!(scale.available_tensions_for_degree(4), [ninth, sharp_eleventh, thirteenth]);
+    ///
+    /// let scale = Scale::harmonic_minor(Pitch::A);
+    /// let flat_ninth = Interval::new(13);
+    /// assert_eq!(
+    ///     scale.available_tensions_for_degree(5),
+    ///     [flat_ninth, Interval::MINOR_THIRTEENTH]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn available_tensions_for_degree(self, degree: u8) -> Vec<Interval> {
+        let pitches: Vec<Pitch> = self.map(Into::into).collect();
+        let len = pitches.len();
+        let Some(idx) = degree.checked_sub(1).map(usize::from) else {
+            return Vec::new();
+        };
+        if len != 7 || idx >= len {
+            return Vec::new();
+        }
+
+        let root = pitches[idx];
+        let above_root = |offset: usize| (pitches[(idx + offset) % len] - root).semitones();
+        let chord_tones = [0, above_root(2), above_root(4), above_root(6)];
+        let is_dominant = chord_tones == [0, 4, 7, 10];
+
+        [1, 3, 5]
+            .into_iter()
+            .map(above_root)
+            .filter(|&tension| {
+                chord_tones.iter().enumerate().all(|(tone_idx, &tone)| {
+                    let is_half_step_above = (tension + 12 - tone) % 12 == 1;
+                    let is_allowed = is_dominant && matches!(tone_idx, 0 | 2);
+                    !is_half_step_above || is_allowed
+                })
+            })
+            .map(|tension| Interval::new(tension + Interval::OCTAVE.semitones()))
+            .collect()
+    }
+
+    /// Returns the just intonation frequency ratio of each note above the root,
+    /// followed by the octave.
+    ///
+    /// Intervals use the ratios of [`Interval::just_ratio`].
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(
+    ///     Scale::major(Pitch::C).to_just_intonation_ratios(),
+    ///     [(1, 1), (9, 8), (5, 4), (4, 3), (3, 2), (5, 3), (15, 8), (2, 1)]
+    /// );
+    /// assert_eq!(
+    ///     Scale::natural_minor(Pitch::A).to_just_intonation_ratios(),
+    ///     [(1, 1), (9, 8), (6, 5), (4, 3), (3, 2), (8, 5), (9, 5), (2, 1)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_just_intonation_ratios(self) -> Vec<(u32, u32)> {
+        let mut ratios: Vec<_> = self
+            .pitch_intervals()
+            .map(|interval| interval.just_ratio())
+            .collect();
+        ratios.push((2, 1));
+        ratios
+    }
+
+    /// Returns how many cents each of the [just ratios](Self::to_just_intonation_ratios)
+    /// of this scale is from the same note in equal temperament.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let deviations = Scale::major(Pitch::C).to_equal_temperament_deviations();
+    /// assert_eq!(deviations[0], 0.);
+    ///
+    /// // A pure major third is about 14 cents flat
+    /// assert_eq!(deviations[2].round(), -14.);
+    /// assert_eq!(deviations[4].round(), 2.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_equal_temperament_deviations(self) -> Vec<f32> {
+        let mut semitones: Vec<u8> = self
+            .pitch_intervals()
+            .map(|interval| interval.semitones())
+            .collect();
+        semitones.push(Interval::OCTAVE.semitones());
+
+        self.to_just_intonation_ratios()
+            .into_iter()
+            .zip(semitones)
+            .map(|((numerator, denominator), semitones)| {
+                let cents = 1200. * (numerator as f32 / denominator as f32).log2();
+                cents - 100. * semitones as f32
+            })
+            .collect()
+    }
+
+    /// Returns how bright this scale sounds compared to the major scale on the same root.
+    ///
+    /// Each raised degree adds one and each lowered degree subtracts one (two for double
+    /// accidentals), divided by the number of notes, so Lydian is the brightest mode
+    /// and Locrian is the darkest.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::major(Pitch::C).brightness(), 0.);
+    /// assert_eq!(Scale::lydian(Pitch::C).brightness(), 1. / 7.);
+    /// assert_eq!(Scale::locrian(Pitch::C).brightness(), -5. / 7.);
+    /// assert_eq!(Scale::minor_pentatonic(Pitch::C).brightness(), -2. / 5.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn brightness(self) -> f32 {
+        let len = self.clone().count();
+        if len == 0 {
+            return 0.;
+        }
+
+        let alterations: i32 = self
+            .compare_to_major()
+            .into_iter()
+            .map(|(_, accidental)| match accidental {
+                Accidental::DoubleFlat => -2,
+                Accidental::Flat => -1,
+                Accidental::Natural => 0,
+                Accidental::Sharp => 1,
+                Accidental::DoubleSharp => 2,
+            })
+            .sum();
+        alterations as f32 / len as f32
+    }
+
+    /// Returns true if this scale is [brighter](Self::brightness) than `other`.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert!(Scale::lydian(Pitch::F).brighter_than(Scale::major(Pitch::F)));
+    /// assert!(!Scale::aeolian(Pitch::A).brighter_than(Scale::dorian(Pitch::D)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn brighter_than<V, W>(self, other: Scale<V, W>) -> bool
+    where
+        V: Clone + Into<Pitch>,
+        Scale<V, W>: Iterator + Clone,
+        <Scale<V, W> as Iterator>::Item: Into<Pitch>,
+    {
+        self.brightness() > other.brightness()
+    }
+
+    /// Returns true if this scale is [darker](Self::brightness) than `other`.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert!(Scale::phrygian(Pitch::E).darker_than(Scale::aeolian(Pitch::E)));
+    /// assert!(!Scale::mixolydian(Pitch::G).darker_than(Scale::dorian(Pitch::G)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn darker_than<V, W>(self, other: Scale<V, W>) -> bool
+    where
+        V: Clone + Into<Pitch>,
+        Scale<V, W>: Iterator + Clone,
+        <Scale<V, W> as Iterator>::Item: Into<Pitch>,
+    {
+        self.brightness() < other.brightness()
+    }
+
+    /// Returns how this scale is related to `other`.
+    /// ```
+    /// use staff::{Natural, Note, Pitch, Scale};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let comparison = Scale::dorian(Pitch::D).compare(Scale::major(Pitch::C));
+    /// assert!(!comparison.is_transposition);
+    /// assert_eq!(comparison.is_mode_of, Some(2));
+    /// assert!(comparison.is_subset_of && comparison.is_superset_of);
+    ///
+    /// let comparison = Scale::major(Pitch::C).compare(Scale::major(Pitch::G));
+    /// assert_eq!(comparison.transposition_interval, Some(7));
+    /// assert_eq!(comparison.shared_pitches.len(), 6);
+    ///
+    /// let c_sharp = Scale::major(Note::sharp(Natural::C));
+    /// let comparison = c_sharp.compare(Scale::major(Note::flat(Natural::D)));
+    /// assert!(comparison.is_enharmonic);
+    /// assert!(!comparison.is_identical);
+    ///
+    /// let major = Scale::new(Pitch::C, ScaleType::Major.intervals());
+    /// let comparison = Scale::major_pentatonic(Pitch::C).compare(major);
+    /// assert!(comparison.is_subset_of && !comparison.is_superset_of);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn compare(self, other: Self) -> ScaleComparison
+    where
+        T: PartialEq,
+    {
+        let root: Pitch = self.root.clone().into();
+        let other_root: Pitch = other.root.clone().into();
+        let intervals = self.pitch_intervals();
+        let other_intervals = other.pitch_intervals();
+
+        let is_same_intervals = intervals == other_intervals;
+        let is_enharmonic = is_same_intervals && root == other_root;
+        let is_mode_of = (1..=other.clone().count() as u8).find(|&degree| {
+            other
+                .clone()
+                .mode_at(degree)
+                .is_ok_and(|mode| mode.root == root && mode.pitch_intervals() == intervals)
+        });
+
+        let pitches = self.clone().pitch_set();
+        let other_pitches = other.clone().pitch_set();
+
+        ScaleComparison {
+            is_identical: is_enharmonic && self.root == other.root,
+            is_enharmonic,
+            is_transposition: is_same_intervals && root != other_root,
+            transposition_interval: is_same_intervals.then(|| (other_root - root).semitones()),
+            is_mode_of,
+            is_subset_of: pitches.bits & !other_pitches.bits == 0,
+            is_superset_of: other_pitches.bits & !pitches.bits == 0,
+            shared_pitches: Self::pivot_notes(self, other),
+        }
+    }
+
+    /// Returns the pitches of `from` that are also in `to`, in the order they appear in `from`.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let pitches = Scale::pivot_notes(Scale::major(Pitch::C), Scale::major(Pitch::G));
+    /// assert_eq!(pitches, [Pitch::C, Pitch::D, Pitch::E, Pitch::G, Pitch::A, Pitch::B]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn pivot_notes<V, W>(from: Self, to: Scale<V, W>) -> Vec<Pitch>
+    where
+        Scale<V, W>: Iterator,
+        <Scale<V, W> as Iterator>::Item: Into<Pitch>,
+    {
+        let to: PitchSet = to.map(Into::into).collect();
+        from.map(Into::into)
+            .filter(|pitch| to.contains(*pitch))
+            .collect()
+    }
+
+    /// Returns the pitches of this scale that are not in `other`, in scale order.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let pitches = Scale::major(Pitch::C).unique_to(Scale::major(Pitch::D));
+    /// assert_eq!(pitches, [Pitch::C, Pitch::F]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_to<V, W>(self, other: Scale<V, W>) -> Vec<Pitch>
+    where
+        Scale<V, W>: Iterator,
+        <Scale<V, W> as Iterator>::Item: Into<Pitch>,
+    {
+        let other: PitchSet = other.map(Into::into).collect();
+        self.map(Into::into)
+            .filter(|pitch| !other.contains(*pitch))
+            .collect()
+    }
+
+    /// Returns the scale tones strictly between `from` and `to`,
+    /// moving in whichever direction is shorter (ascending on a tie).
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let scale = Scale::major(Pitch::C);
+    /// assert_eq!(scale.clone().passing_tones(Pitch::C, Pitch::F), [Pitch::D, Pitch::E]);
+    /// assert_eq!(scale.passing_tones(Pitch::C, Pitch::G), [Pitch::B, Pitch::A]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn passing_tones(self, from: Pitch, to: Pitch) -> Vec<Pitch> {
+        let pitches = self.pitch_set();
+        let up = (to - from).semitones();
+        let down = (from - to).semitones();
+
+        (1..up.min(down))
+            .map(|step| {
+                if up <= down {
+                    from + Interval::new(step)
+                } else {
+                    from - Interval::new(step)
+                }
+            })
+            .filter(|pitch| pitches.contains(*pitch))
+            .collect()
+    }
+
+    /// Returns the nearest scale tones above and below `pitch`, as `(upper, lower)`.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let scale = Scale::major(Pitch::C);
+    /// assert_eq!(scale.clone().neighbor_tones(Pitch::E), (Pitch::F, Pitch::D));
+    /// assert_eq!(scale.neighbor_tones(Pitch::FSharp), (Pitch::G, Pitch::F));
+    /// ```
+    pub fn neighbor_tones(self, pitch: Pitch) -> (Pitch, Pitch) {
+        let pitches = self.pitch_set();
+        let neighbor = |f: fn(Pitch, Interval) -> Pitch| {
+            (1..Interval::OCTAVE.semitones())
+                .map(|step| f(pitch, Interval::new(step)))
+                .find(|pitch| pitches.contains(*pitch))
+                .unwrap_or(pitch)
+        };
+
+        (neighbor(Pitch::add_interval), neighbor(Pitch::sub_interval))
+    }
+
+    /// Returns how strongly `from` is pulled towards `to` in this scale, from 0 to 1.
+    ///
+    /// The tonic, the dominant, and the third above the tonic are the most stable tones,
+    /// other scale tones are less stable, and notes outside of the scale are the least stable.
+    /// The score is how much more stable `to` is than `from`, divided by the
+    /// number of semitones between them.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// let scale = Scale::major(Pitch::C);
+    ///
+    /// let leading_tone = scale.clone().tonal_gravity(Pitch::B, Pitch::C);
+    /// assert!(leading_tone > scale.clone().tonal_gravity(Pitch::F, Pitch::E));
+    /// assert!(leading_tone > scale.clone().tonal_gravity(Pitch::D, Pitch::C));
+    ///
+    /// assert_eq!(scale.tonal_gravity(Pitch::C, Pitch::B), 0.);
+    /// ```
+    pub fn tonal_gravity(self, from: Pitch, to: Pitch) -> f32 {
+        let root: Pitch = self.root.clone().into();
+        let pitches = self.pitch_set();
+        let stability = |pitch: Pitch| {
+            if !pitches.contains(pitch) {
+                return 0.;
+            }
+            match pitch - root {
+                Interval::UNISON => 1.,
+                Interval::PERFECT_FIFTH => 0.8,
+                Interval::MINOR_THIRD | Interval::MAJOR_THIRD => 0.6,
+                _ => 0.2,
+            }
+        };
+
+        let up = (to - from).semitones();
+        let distance = up.min(Interval::OCTAVE.semitones() - up);
+        if distance == 0 {
+            return 0.;
+        }
+
+        f32::max(stability(to) - stability(from), 0.) / distance as f32
+    }
+
+    /// Returns the name of this scale if it is a mode of the major, harmonic minor
+    /// or melodic minor scale.
+    ///
+    /// The seventh mode of melodic minor is also known as the altered scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::major(Pitch::C).mode_name(), Some("Ionian"));
+    /// assert_eq!(Scale::mixolydian(Pitch::G).mode_name(), Some("Mixolydian"));
+    /// assert_eq!(Scale::melodic_minor(Pitch::A).mode_name(), Some("Jazz Minor"));
+    ///
+    /// let modes = Scale::harmonic_minor_modes(Pitch::A);
+    /// assert_eq!(modes[4].clone().mode_name(), Some("Phrygian Dominant"));
+    ///
+    /// assert_eq!(Scale::blues(Pitch::A).mode_name(), None);
+    /// ```
+    pub fn mode_name(self) -> Option<&'static str> {
+        let families = [
+            (
+                ScaleIntervals::major(),
+                [
+                    "Ionian",
+                    "Dorian",
+                    "Phrygian",
+                    "Lydian",
+                    "Mixolydian",
+                    "Aeolian",
+                    "Locrian",
+                ],
+            ),
+            (
+                ScaleIntervals::harmonic_minor(),
+                [
+                    "Harmonic Minor",
+                    "Locrian ♮6",
+                    "Ionian ♯5",
+                    "Dorian ♯4",
+                    "Phrygian Dominant",
+                    "Lydian ♯2",
+                    "Super Locrian ♭♭7",
+                ],
+            ),
+            (
+                ScaleIntervals::melodic_minor(),
+                [
+                    "Jazz Minor",
+                    "Dorian ♭2",
+                    "Lydian Augmented",
+                    "Lydian Dominant",
+                    "Mixolydian ♭6",
+                    "Locrian ♮2",
+                    "Super Locrian",
+                ],
+            ),
+        ];
+
+        let intervals = self.pitch_intervals();
+        families.into_iter().find_map(|(family, names)| {
+            Scale::modes_of(Pitch::C, family)
+                .into_iter()
+                .zip(names)
+                .find(|(mode, _)| mode.intervals == intervals)
+                .map(|(_, name)| name)
+        })
+    }
+
+    /// Returns the number of the church mode this scale is, from 1 (Ionian) to 7 (Locrian).
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::phrygian(Pitch::E).mode_number(), Some(3));
+    /// assert_eq!(Scale::harmonic_minor(Pitch::A).mode_number(), None);
+    /// ```
+    pub fn mode_number(self) -> Option<u8> {
+        let intervals = self.pitch_intervals();
+        Scale::modes_of(Pitch::C, ScaleIntervals::major())
+            .into_iter()
+            .position(|mode| mode.intervals == intervals)
+            .map(|idx| idx as u8 + 1)
+    }
+
+    /// Returns the name of the church mode this scale is.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert_eq!(Scale::natural_minor(Pitch::A).church_mode_name(), Some("Aeolian"));
+    /// assert_eq!(Scale::whole_tone(Pitch::C).church_mode_name(), None);
+    /// ```
+    pub fn church_mode_name(self) -> Option<&'static str> {
+        let names = [
+            "Ionian",
+            "Dorian",
+            "Phrygian",
+            "Lydian",
+            "Mixolydian",
+            "Aeolian",
+            "Locrian",
+        ];
+        self.mode_number().map(|number| names[number as usize - 1])
+    }
+
+    /// Returns true if this scale is one of the seven church modes.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert!(Scale::lydian(Pitch::F).is_church_mode());
+    /// assert!(!Scale::blues(Pitch::A).is_church_mode());
+    /// ```
+    pub fn is_church_mode(self) -> bool {
+        self.mode_number().is_some()
+    }
+
+    /// Returns the key signature of this scale if it is a mode of the major scale.
+    ///
+    /// Other scales, like pentatonic or harmonic minor scales, return `None`.
+    /// ```
+    /// use staff::{Key, Pitch, Scale};
+    ///
+    /// let key = Scale::dorian(Pitch::E).get_key_signature().unwrap();
+    /// assert_eq!(key, Key::major(Pitch::D));
+    /// assert_eq!(key.sharps, 2);
+    ///
+    /// let key = Scale::natural_minor(Pitch::D).get_key_signature().unwrap();
+    /// assert_eq!(key.flats(), 1);
+    /// assert!(!key.is_sharp());
+    ///
+    /// assert_eq!(Scale::major_pentatonic(Pitch::C).get_key_signature(), None);
+    /// ```
+    pub fn get_key_signature(self) -> Option<Key> {
+        let root: Pitch = self.root.clone().into();
+        let number = self.mode_number()?;
+
+        let degree = ScaleIntervals::major().nth(number as usize - 1)?;
+        Some(Key::major(root.sub_interval(degree)))
+    }
+
+    /// Returns true if this scale is one of the modes of the harmonic minor scale.
+    /// ```
+    /// use staff::{Pitch, Scale};
+    ///
+    /// assert!(Scale::harmonic_minor(Pitch::A).is_harmonic_minor_mode());
+    /// assert!(Scale::harmonic_minor_modes(Pitch::D)[4].clone().is_harmonic_minor_mode());
+    /// assert!(!Scale::major(Pitch::C).is_harmonic_minor_mode());
+    /// ```
+    pub fn is_harmonic_minor_mode(self) -> bool {
+        let intervals = self.pitch_intervals();
+        Scale::harmonic_minor_modes(Pitch::C)
+            .into_iter()
+            .any(|mode| mode.intervals == intervals)
+    }
+
+    fn pitch_set(self) -> PitchSet {
+        self.map(Into::into).collect()
+    }
+
+    /// Returns the intervals of the remaining notes in this scale from its root, within an octave.
+    pub(crate) fn pitch_intervals(&self) -> ScaleIntervals {
+        let root: Pitch = self.root.clone().into();
+        self.clone().map(|note| note.into() - root).collect()
+    }
+}
+
+impl<T, U> Scale<T, U>
+where
+    Self: Iterator<Item = MidiNote> + Clone,
+{
+    /// Returns the cadences in this scale, including the phrygian half cadence
+    /// for natural and harmonic minor scales.
+    /// ```
+    /// use staff::{midi, Chord, Scale};
+    /// use staff::chord::Cadence;
+    ///
+    /// let cadences = Scale::major(midi!(C, 4)).cadential_chords();
+    ///
+    /// assert_eq!(
+    ///     cadences[0],
+    ///     Cadence::Authentic(Chord::major(midi!(G, 4)), Chord::major(midi!(C, 4)))
+    /// );
+    ///
+    /// let is_phrygian_half = |cadence: &Cadence| matches!(cadence, Cadence::PhrygianHalf(..));
+    /// assert!(Scale::natural_minor(midi!(A, 4)).cadential_chords().iter().any(is_phrygian_half));
+    /// assert!(!Scale::dorian(midi!(D, 4)).cadential_chords().iter().any(is_phrygian_half));
+    /// assert!(!Scale::phrygian(midi!(E, 4)).cadential_chords().iter().any(is_phrygian_half));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cadential_chords(self) -> Vec<Cadence> {
+        let triad = |degree| self.chord(degree, 3);
+        let (Some(tonic), Some(subdominant), Some(dominant), Some(submediant)) =
+            (triad(1), triad(4), triad(5), triad(6))
+        else {
+            return Vec::new();
+        };
+
+        // Only minor scales with a major second and minor sixth have the iv6 to V cadence
+        let pitches: PitchSet = self.clone().map(|midi| midi.pitch()).collect();
+        let root = tonic.root.pitch();
+        let is_minor = [
+            Interval::MAJOR_SECOND,
+            Interval::MINOR_THIRD,
+            Interval::PERFECT_FIFTH,
+            Interval::MINOR_SIXTH,
+        ]
+        .into_iter()
+        .all(|interval| pitches.contains(root + interval));
+        let mut cadences = vec![
+            Cadence::Authentic(dominant.clone(), tonic.clone()),
+            Cadence::Half(tonic.clone(), dominant.clone()),
+            Cadence::Plagal(subdominant.clone(), tonic),
+            Cadence::Deceptive(dominant.clone(), submediant),
+        ];
+
+        if is_minor {
+            cadences.push(Cadence::PhrygianHalf(
+                subdominant.first_inversion(),
+                Chord::major(dominant.root),
+            ));
+        }
+
+        cadences
+    }
+
+    /// Returns the triads of `from` that are also triads of `to`,
+    /// with the Roman numeral of each one in both scales.
+    /// ```
+    /// use staff::{midi, Scale};
+    ///
+    /// let pivots = Scale::find_pivot_chords(Scale::major(midi!(C, 4)), Scale::major(midi!(G, 4)));
+    ///
+    /// let names: Vec<_> = pivots
+    ///     .iter()
+    ///     .map(|(chord, from, to)| format!("{} {} {}", chord, from, to))
+    ///     .collect();
+    /// assert_eq!(names, ["C I IV", "Em iii vi", "G V I", "Am vi ii"]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn find_pivot_chords<V, W>(
+        from: Self,
+        to: Scale<V, W>,
+    ) -> Vec<(Chord, RomanNumeral, RomanNumeral)>
+    where
+        Scale<V, W>: Iterator<Item = MidiNote> + Clone,
+    {
+        let numeral = |degree: u8, chord: &Chord| {
+            RomanNumeral::new(degree, Accidental::Natural, TriadQuality::of(chord))
+        };
+        let to_triads: Vec<(u8, Chord)> = (1..=to.clone().count() as u8)
+            .filter_map(|degree| to.chord(degree, 3).map(|chord| (degree, chord)))
+            .collect();
+
+        (1..=from.clone().count() as u8)
+            .filter_map(|degree| {
+                let chord = from.chord(degree, 3)?;
+                let (to_degree, to_chord) = to_triads
+                    .iter()
+                    .find(|(_, other)| other.pitch_set() == chord.pitch_set())?;
+
+                let to_numeral = numeral(*to_degree, to_chord);
+                Some((chord.clone(), numeral(degree, &chord), to_numeral))
+            })
+            .collect()
+    }
+
+    /// Returns a diatonic triad for each group of `beats_per_chord` notes of `melody`,
+    /// with the beat each chord starts on.
+    ///
+    /// Each chord has the most tones in its group of notes,
+    /// preferring the tonic, dominant, and subdominant on a tie.
+    /// ```
+    /// use staff::{midi, Chord, Pitch, Scale};
+    ///
+    /// let melody = [Pitch::E, Pitch::G, Pitch::F, Pitch::D, Pitch::C];
+    /// let chords = Scale::major(midi!(C, 4)).harmonic_rhythm(&melody, 2);
+    ///
+    /// assert_eq!(
+    ///     chords,
+    ///     [
+    ///         (Chord::major(midi!(C, 4)), 0),
+    ///         (Chord::minor(midi!(D, 4)), 2),
+    ///         (Chord::major(midi!(C, 4)), 4),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn harmonic_rhythm(self, melody: &[Pitch], beats_per_chord: usize) -> Vec<(Chord, usize)> {
+        if beats_per_chord == 0 {
+            return Vec::new();
+        }
+
+        let triads: Vec<Chord> = [1, 5, 4, 6, 2, 3, 7]
+            .into_iter()
+            .filter_map(|degree| self.chord(degree, 3))
+            .collect();
+
+        melody
+            .chunks(beats_per_chord)
+            .enumerate()
+            .filter_map(|(idx, notes)| {
+                // Reversed so that the first of the preferred triads wins a tie
+                let chord = triads.iter().rev().max_by_key(|triad| {
+                    let pitches = triad.pitch_set();
+                    notes
+                        .iter()
+                        .filter(|pitch| pitches.contains(**pitch))
+                        .count()
+                })?;
+                Some((chord.clone(), idx * beats_per_chord))
+            })
+            .collect()
+    }
+
+    /// Returns the triads borrowed from the other modes of the major scale on this scale's root
+    /// that aren't diatonic to this scale, paired with the mode each one is first found in.
+    ///
+    /// Modes are searched in order from dorian to locrian.
+    /// ```
+    /// use staff::{midi, Scale};
+    /// use staff::scale::ScaleType;
+    ///
+    /// let chords = Scale::major(midi!(C, 4)).modal_mixture_chords();
+    ///
+    /// // The minor i chord from C dorian
+    /// let (chord, mode) = chords[0].clone();
+    /// assert_eq!(chord.to_string(), "Cm");
+    /// assert_eq!(mode.scale_type(), ScaleType::Dorian);
+    ///
+    /// assert!(chords.iter().any(|(chord, _)| chord.to_string() == "A#"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn modal_mixture_chords(self) -> Vec<(Chord, DiatonicScale<MidiNote>)> {
+        let Some(root) = self.clone().next() else {
+            return Vec::new();
+        };
+        let pitches: PitchSet = self.map(|midi| midi.pitch()).collect();
+
+        let modes = [
+            Scale::dorian(root),
+            Scale::phrygian(root),
+            Scale::lydian(root),
+            Scale::mixolydian(root),
+            Scale::aeolian(root),
+            Scale::locrian(root),
+        ];
+
+        let mut chords: Vec<(Chord, DiatonicScale<MidiNote>)> = Vec::new();
+        for mode in modes {
+            for degree in 1..=7 {
+                let Some(chord) = mode.chord(degree, 3) else {
+                    continue;
+                };
+
+                let chord_pitches = chord.clone().into_iter().map(|midi| midi.pitch());
+                let is_diatonic = chord_pitches.collect::<PitchSet>().bits & !pitches.bits == 0;
+                let is_found = chords.iter().any(|(other, _)| *other == chord);
+                if !is_diatonic && !is_found {
+                    chords.push((chord, mode.clone()));
+                }
+            }
+        }
+        chords
+    }
+
+    /// Returns the chord of `size` notes stacked in thirds on the given (1-indexed) degree,
+    /// using only notes from this scale.
+    /// ```
+    /// use staff::{midi, Chord, Scale};
+    /// use staff::scale::Error;
+    ///
+    /// let scale = Scale::major(midi!(C, 4));
+    ///
+    /// assert_eq!(scale.clone().get_chord(5, 4), Ok(Chord::seventh(midi!(G, 4))));
+    /// assert_eq!(scale.clone().get_chord(8, 3), Err(Error::InvalidDegree(8)));
+    /// assert_eq!(scale.get_chord(1, 8), Err(Error::InvalidChordSize(8)));
+    /// ```
+    pub fn get_chord(self, degree: u8, size: u8) -> Result<Chord, Error> {
+        if size < 2 || size as usize > self.clone().count() {
+            return Err(Error::InvalidChordSize(size));
+        }
+        self.chord(degree, size).ok_or(Error::InvalidDegree(degree))
+    }
+
+    /// Returns the chord of `size` notes stacked in thirds on the given (1-indexed) degree.
+    pub(crate) fn chord(&self, degree: u8, size: u8) -> Option<Chord> {
+        let len = self.clone().count();
+        let idx = degree.checked_sub(1)? as usize;
+        if idx >= len {
+            return None;
+        }
+
+        let root = self.clone().nth(idx)?;
+        let mut chord = Chord::new(root).root();
+        for i in 1..size as usize {
+            let pos = idx + i * 2;
+            let octaves = Interval::new((pos / len) as u8 * Interval::OCTAVE.semitones());
+            let midi = self.clone().nth(pos % len)? + octaves;
+            chord.intervals.push(midi - root);
+        }
+
+        Some(chord)
+    }
 }
 
 impl<T, U> Iterator for Scale<T, U>
@@ -69,6 +1469,25 @@ where
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// No scale degrees were given, like to [`Chord::from_scale_degrees`](crate::Chord::from_scale_degrees)
+    Empty,
+    InvalidDegree(u8),
+    /// A chord size less than 2 or more than the number of notes in the scale
+    InvalidChordSize(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Empty scale degrees"),
+            Self::InvalidDegree(degree) => write!(f, "Invalid scale degree `{}`", degree),
+            Self::InvalidChordSize(size) => write!(f, "Invalid chord size `{}`", size),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Natural, Note, Scale};
@@ -88,6 +1507,22 @@ mod tests {
         ]))
     }
 
+    #[test]
+    fn test_triple_sharp_falls_back() {
+        // The leading tone would be F triple sharp
+        let scale = Scale::harmonic_minor(Note::double_sharp(Natural::G));
+
+        assert!(scale.eq([
+            Note::double_sharp(Natural::G),
+            Note::double_sharp(Natural::A),
+            Note::sharp(Natural::B),
+            Note::double_sharp(Natural::C),
+            Note::double_sharp(Natural::D),
+            Note::sharp(Natural::E),
+            Note::sharp(Natural::G),
+        ]))
+    }
+
     #[test]
     fn test_f_sharp_major() {
         let scale = Scale::major(Note::sharp(Natural::F));
@@ -133,6 +1568,36 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_f_flat_locrian() {
+        let scale = Scale::locrian(Note::flat(Natural::F));
+
+        assert!(scale.eq([
+            Note::flat(Natural::F),
+            Note::double_flat(Natural::G),
+            Note::double_flat(Natural::A),
+            Note::double_flat(Natural::B),
+            Note::double_flat(Natural::C),
+            Note::double_flat(Natural::D),
+            Note::double_flat(Natural::E),
+        ]));
+    }
+
+    #[test]
+    fn test_a_sharp_lydian() {
+        let scale = Scale::lydian(Note::sharp(Natural::A));
+
+        assert!(scale.eq([
+            Note::sharp(Natural::A),
+            Note::sharp(Natural::B),
+            Note::double_sharp(Natural::C),
+            Note::double_sharp(Natural::D),
+            Note::sharp(Natural::E),
+            Note::double_sharp(Natural::F),
+            Note::double_sharp(Natural::G),
+        ]));
+    }
+
     #[test]
     fn test_c_lydian() {
         let scale = Scale::lydian(Note::from(Natural::C));
@@ -0,0 +1,115 @@
+use super::ScaleIntervals;
+use core::fmt;
+
+/// Pentatonic scale types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PentatonicType {
+    Major,
+    Minor,
+}
+
+/// Known scale types that can be identified by their intervals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScaleType {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Aeolian,
+    Locrian,
+    WholeTone,
+    Diminished,
+    Pentatonic(PentatonicType),
+    Blues,
+    Custom(ScaleIntervals),
+}
+
+impl ScaleType {
+    /// All known scale types, in order of preference when identifying a scale.
+    pub const ALL: [Self; 15] = [
+        Self::Major,
+        Self::NaturalMinor,
+        Self::HarmonicMinor,
+        Self::MelodicMinor,
+        Self::Dorian,
+        Self::Phrygian,
+        Self::Lydian,
+        Self::Mixolydian,
+        Self::Aeolian,
+        Self::Locrian,
+        Self::WholeTone,
+        Self::Diminished,
+        Self::Pentatonic(PentatonicType::Major),
+        Self::Pentatonic(PentatonicType::Minor),
+        Self::Blues,
+    ];
+
+    /// Returns the intervals of this scale type from its root.
+    pub fn intervals(self) -> ScaleIntervals {
+        match self {
+            Self::Major => ScaleIntervals::major(),
+            Self::NaturalMinor | Self::Aeolian => ScaleIntervals::natural_minor(),
+            Self::HarmonicMinor => ScaleIntervals::harmonic_minor(),
+            Self::MelodicMinor => ScaleIntervals::melodic_minor(),
+            Self::Dorian => ScaleIntervals::dorian(),
+            Self::Phrygian => ScaleIntervals::phrygian(),
+            Self::Lydian => ScaleIntervals::lydian(),
+            Self::Mixolydian => ScaleIntervals::mixolydian(),
+            Self::Locrian => ScaleIntervals::locrian(),
+            Self::WholeTone => ScaleIntervals::whole_tone(),
+            Self::Diminished => ScaleIntervals::diminished(),
+            Self::Pentatonic(PentatonicType::Major) => ScaleIntervals::major_pentatonic(),
+            Self::Pentatonic(PentatonicType::Minor) => ScaleIntervals::minor_pentatonic(),
+            Self::Blues => ScaleIntervals::blues(),
+            Self::Custom(intervals) => intervals,
+        }
+    }
+
+    /// Returns the human-readable name of this scale type.
+    /// ```
+    /// use staff::scale::ScaleType;
+    ///
+    /// assert_eq!(ScaleType::HarmonicMinor.display_name(), "Harmonic Minor");
+    /// ```
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Major => "Major",
+            Self::NaturalMinor => "Natural Minor",
+            Self::HarmonicMinor => "Harmonic Minor",
+            Self::MelodicMinor => "Melodic Minor",
+            Self::Dorian => "Dorian",
+            Self::Phrygian => "Phrygian",
+            Self::Lydian => "Lydian",
+            Self::Mixolydian => "Mixolydian",
+            Self::Aeolian => "Aeolian",
+            Self::Locrian => "Locrian",
+            Self::WholeTone => "Whole Tone",
+            Self::Diminished => "Diminished",
+            Self::Pentatonic(PentatonicType::Major) => "Major Pentatonic",
+            Self::Pentatonic(PentatonicType::Minor) => "Minor Pentatonic",
+            Self::Blues => "Blues",
+            Self::Custom(_) => "Custom",
+        }
+    }
+}
+
+impl From<ScaleIntervals> for ScaleType {
+    fn from(intervals: ScaleIntervals) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|scale_type| scale_type.intervals() == intervals)
+            .unwrap_or(Self::Custom(intervals))
+    }
+}
+
+impl fmt::Display for ScaleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
@@ -0,0 +1,94 @@
+//! Scores of multiple parts
+
+use crate::{
+    midi::{self, MidiFile, MidiTrack, PianoRollNote},
+    time::{Tempo, TimeSignature},
+    Key,
+};
+
+/// The notes played by one instrument in a [`Score`], timed in quarter note beats.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Part {
+    pub instrument: String,
+    pub events: Vec<PianoRollNote>,
+}
+
+impl Part {
+    pub fn new(instrument: impl Into<String>, events: Vec<PianoRollNote>) -> Self {
+        Self {
+            instrument: instrument.into(),
+            events,
+        }
+    }
+}
+
+/// A piece of music for one or more parts played together.
+/// ```
+/// use staff::{midi, Key, Pitch};
+/// use staff::midi::PianoRollNote;
+/// use staff::score::{Part, Score};
+/// use staff::time::{DurationKind, Tempo, TimeSignature};
+///
+/// let mut score = Score::new(
+///     "Duet",
+///     Key::major(Pitch::C),
+///     TimeSignature::new(DurationKind::Quarter, 4),
+///     Tempo::new(100.),
+/// );
+/// score.parts.push(Part::new("Flute", vec![PianoRollNote::new(midi!(E, 5), 0., 2., 90)]));
+/// score.parts.push(Part::new("Cello", vec![PianoRollNote::new(midi!(C, 3), 0., 2., 90)]));
+///
+/// let file = score.to_midi_file();
+/// assert_eq!(file.tracks.len(), 2);
+/// assert_eq!(file.tracks[0].name, "Flute");
+///
+/// let bytes = file.to_bytes();
+/// assert_eq!(&bytes[8..12], [0, 1, 0, 3]);
+///
+/// // The key signature of C major
+/// assert!(bytes.windows(5).any(|event| event == [0xFF, 0x59, 0x02, 0, 0]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Score {
+    pub title: String,
+    pub key: Key,
+    pub time_signature: TimeSignature,
+    pub tempo: Tempo,
+    pub parts: Vec<Part>,
+}
+
+impl Score {
+    /// Creates a score without any parts.
+    pub fn new(
+        title: impl Into<String>,
+        key: Key,
+        time_signature: TimeSignature,
+        tempo: Tempo,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            key,
+            time_signature,
+            tempo,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Returns a format 1 MIDI file with a track named after the instrument of each part.
+    ///
+    /// Each part is played on its own channel, in order, as described in [`MidiFile::to_bytes`].
+    /// The key is written as a key signature in the first track along with the tempo.
+    pub fn to_midi_file(self) -> MidiFile {
+        let mut file = MidiFile::new(self.tempo, self.time_signature);
+        file.key = Some(self.key);
+        file.tracks = self
+            .parts
+            .into_iter()
+            .map(|part| {
+                let messages = midi::note_messages(&part.events, file.ticks_per_beat);
+                MidiTrack::new(part.instrument, messages)
+            })
+            .collect();
+        file
+    }
+}
@@ -17,6 +17,79 @@ impl IntervalSet {
     }
 }
 
+/// The fraction of the strongest pitch class energy another pitch class needs
+/// to be detected in a spectrum.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpectrumThreshold(pub f32);
+
+#[cfg(feature = "std")]
+impl Default for SpectrumThreshold {
+    fn default() -> Self {
+        Self(0.3)
+    }
+}
+
+#[cfg(feature = "std")]
+impl PitchSet {
+    /// Returns the pitch classes with significant energy in the FFT `magnitudes`,
+    /// using the default [`SpectrumThreshold`].
+    ///
+    /// `magnitudes` are the bins from 0 Hz up to half of `sample_rate`.
+    /// ```
+    /// use staff::{midi, Chord, Pitch};
+    /// use staff::set::PitchSet;
+    ///
+    /// let sample_rate = 8192.;
+    /// let mut magnitudes = [0f32; 4096];
+    /// for midi in [midi!(C, 4), midi!(E, 4), midi!(G, 4)] {
+    ///     magnitudes[midi.frequency().round() as usize] = 1.;
+    /// }
+    ///
+    /// let pitches: Vec<Pitch> = PitchSet::from_spectrum(&magnitudes, sample_rate).collect();
+    /// assert_eq!(pitches, [Pitch::C, Pitch::E, Pitch::G]);
+    ///
+    /// let (chord, _) = Chord::identify_all(&pitches)[0].clone();
+    /// assert_eq!(chord.to_string(), "C");
+    /// ```
+    pub fn from_spectrum(magnitudes: &[f32], sample_rate: f32) -> Self {
+        Self::from_spectrum_with_threshold(magnitudes, sample_rate, SpectrumThreshold::default())
+    }
+
+    /// Returns the pitch classes with at least `threshold` of the energy of the
+    /// strongest pitch class in the FFT `magnitudes`.
+    pub fn from_spectrum_with_threshold(
+        magnitudes: &[f32],
+        sample_rate: f32,
+        threshold: SpectrumThreshold,
+    ) -> Self {
+        let bin_width = sample_rate / (2 * magnitudes.len()) as f32;
+
+        // Sum the energy of each bin in the range of a piano into its pitch class
+        let mut energy = [0f32; 12];
+        for (idx, magnitude) in magnitudes.iter().enumerate().skip(1) {
+            let frequency = idx as f32 * bin_width;
+            if !(27.5..=4200.).contains(&frequency) {
+                continue;
+            }
+            let midi = 69. + 12. * (frequency / 440.).log2();
+            let class = (midi.round() as i32).rem_euclid(12) as usize;
+            energy[class] += magnitude * magnitude;
+        }
+
+        let max = energy.iter().copied().fold(0., f32::max);
+        let mut pitches = Self::default();
+        if max > 0. {
+            for (byte, energy) in energy.into_iter().enumerate() {
+                if energy >= max * threshold.0 {
+                    pitches.push(Pitch::from_byte(byte as u8));
+                }
+            }
+        }
+        pitches
+    }
+}
+
 /// An ordered set of intervals or pitches stored in a single integer.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
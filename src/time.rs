@@ -49,6 +49,7 @@ impl<'a> dioxus::prelude::IntoAttributeValue<'a> for Duration {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TimeSignature {
     pub unit: DurationKind,
     pub beats: u8,
@@ -59,3 +60,35 @@ impl TimeSignature {
         Self { unit, beats }
     }
 }
+
+/// Tempo in quarter note beats per minute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tempo {
+    pub bpm: f64,
+}
+
+impl Tempo {
+    pub fn new(bpm: f64) -> Self {
+        Self { bpm }
+    }
+
+    /// Returns the length of a beat in microseconds, as stored in MIDI files.
+    /// ```
+    /// use staff::time::Tempo;
+    ///
+    /// assert_eq!(Tempo::new(120.).micros_per_beat(), 500_000);
+    /// ```
+    pub fn micros_per_beat(self) -> u32 {
+        (60_000_000. / self.bpm) as u32
+    }
+
+    pub fn from_micros_per_beat(micros: u32) -> Self {
+        Self::new(60_000_000. / micros as f64)
+    }
+}
+
+impl Default for Tempo {
+    fn default() -> Self {
+        Self::new(120.)
+    }
+}
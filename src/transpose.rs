@@ -0,0 +1,110 @@
+//! Iterator adapters for transposing pitches and notes
+
+use crate::{
+    note::{AccidentalPreference, Note},
+    Interval, Pitch,
+};
+
+/// Iterator that transposes each pitch by a fixed number of semitones.
+/// ```
+/// use staff::Pitch;
+/// use staff::transpose::TransposeExt;
+///
+/// let pitches = [Pitch::C, Pitch::E, Pitch::G].into_iter().transpose_by(-3);
+/// assert!(pitches.eq([Pitch::A, Pitch::CSharp, Pitch::E]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Transposer<I> {
+    iter: I,
+    interval: Interval,
+}
+
+impl<I> Transposer<I> {
+    pub fn new(iter: I, semitones: i8) -> Self {
+        Self {
+            iter,
+            interval: ascending(semitones),
+        }
+    }
+}
+
+impl<I> Iterator for Transposer<I>
+where
+    I: Iterator<Item = Pitch>,
+{
+    type Item = Pitch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|pitch| pitch + self.interval)
+    }
+}
+
+/// Iterator that transposes each note by a fixed number of semitones,
+/// spelling the results with an [`AccidentalPreference`].
+/// ```
+/// use staff::{Natural, Note};
+/// use staff::note::AccidentalPreference;
+/// use staff::transpose::TransposeExt;
+///
+/// let notes = [Note::C, Note::E, Note::G].into_iter();
+/// let notes = notes.transpose_notes_by(3, AccidentalPreference::Flats);
+/// assert!(notes.eq([Note::flat(Natural::E), Note::G, Note::flat(Natural::B)]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NoteTransposer<I> {
+    iter: I,
+    interval: Interval,
+    preference: AccidentalPreference,
+}
+
+impl<I> NoteTransposer<I> {
+    pub fn new(iter: I, semitones: i8, preference: AccidentalPreference) -> Self {
+        Self {
+            iter,
+            interval: ascending(semitones),
+            preference,
+        }
+    }
+}
+
+impl<I> Iterator for NoteTransposer<I>
+where
+    I: Iterator<Item = Note>,
+{
+    type Item = Note;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|note| {
+            let pitch = Pitch::from(note) + self.interval;
+            Note::from_pitch_with(pitch, self.preference)
+        })
+    }
+}
+
+/// Extension methods for transposing iterators of pitches and notes.
+pub trait TransposeExt: Iterator + Sized {
+    fn transpose_by(self, semitones: i8) -> Transposer<Self>
+    where
+        Self: Iterator<Item = Pitch>,
+    {
+        Transposer::new(self, semitones)
+    }
+
+    fn transpose_notes_by(
+        self,
+        semitones: i8,
+        preference: AccidentalPreference,
+    ) -> NoteTransposer<Self>
+    where
+        Self: Iterator<Item = Note>,
+    {
+        NoteTransposer::new(self, semitones, preference)
+    }
+}
+
+impl<I: Iterator> TransposeExt for I {}
+
+/// Returns the ascending interval within an octave that transposes by `semitones`.
+fn ascending(semitones: i8) -> Interval {
+    Interval::new(semitones.rem_euclid(Interval::OCTAVE.semitones() as i8) as u8)
+}